@@ -0,0 +1,14 @@
+#![warn(clippy::pedantic)]
+use adventofcode::snapshot;
+
+/// Accepts all pending snapshots written by failing snapshot assertions.
+fn main() {
+    let dir = snapshot::snapshot_dir();
+    let accepted = snapshot::accept_pending(&dir).expect("Error accepting snapshots!");
+    if accepted.is_empty() {
+        println!("No pending snapshots in {}", dir.display());
+    }
+    for name in accepted {
+        println!("Accepted: {name}");
+    }
+}
@@ -0,0 +1,220 @@
+use alloc::string::{String, ToString};
+use core::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul},
+    str::FromStr,
+};
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+
+/// Answer of a puzzle. Numbers are kept in the smallest representation that fits them,
+/// `Unsigned` for non-negative, `Signed` for negative and `Big` for all others, so equal
+/// numbers are equal answers, and the arithmetic switches to big integers instead of
+/// overflowing.
+///
+/// Text answers can't be used in arithmetic, doing so panics.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Big(BigInt),
+    Text(String),
+}
+
+impl Answer {
+    /// The number as machine integer if it fits.
+    fn small(&self) -> Option<i128> {
+        match self {
+            Self::Signed(n) => Some(i128::from(*n)),
+            Self::Unsigned(n) => Some(i128::from(*n)),
+            Self::Big(_) | Self::Text(_) => None,
+        }
+    }
+
+    fn big(&self) -> BigInt {
+        match self {
+            Self::Signed(n) => BigInt::from(*n),
+            Self::Unsigned(n) => BigInt::from(*n),
+            Self::Big(n) => n.clone(),
+            Self::Text(text) => panic!("Text Answer {text} in Arithmetic!"),
+        }
+    }
+
+    /// Least common multiple, always non-negative and zero if either number is zero.
+    #[must_use]
+    pub fn lcm(&self, other: &Self) -> Self {
+        match (self.small(), other.small()) {
+            (Some(a), Some(b)) => match crate::math::lcm(a, b) {
+                Some(lcm) => Self::from(lcm),
+                None => Self::from(self.big().lcm(&other.big())),
+            },
+            _ => Self::from(self.big().lcm(&other.big())),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        if let Ok(n) = u64::try_from(n) {
+            Self::Unsigned(n)
+        } else if let Ok(n) = i64::try_from(n) {
+            Self::Signed(n)
+        } else {
+            Self::Big(BigInt::from(n))
+        }
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        i128::try_from(&n).map_or(Self::Big(n), Self::from)
+    }
+}
+
+macro_rules! from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(n: $integer) -> Self {
+                    i128::try_from(n).map_or_else(|_| Self::from(BigInt::from(n)), Self::from)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl FromStr for Answer {
+    type Err = core::convert::Infallible;
+
+    /// Integers become numbers, everything else text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse::<BigInt>()
+            .map_or_else(|_| Self::from(s), Self::from))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed(n) => write!(f, "{n}"),
+            Self::Unsigned(n) => write!(f, "{n}"),
+            Self::Big(n) => write!(f, "{n}"),
+            Self::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl Add for Answer {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        match (self.small(), rhs.small()) {
+            (Some(a), Some(b)) => Self::from(a + b),
+            _ => Self::from(self.big() + rhs.big()),
+        }
+    }
+}
+
+impl AddAssign for Answer {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = core::mem::replace(self, Self::Unsigned(0)) + rhs;
+    }
+}
+
+impl Mul for Answer {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        match (self.small(), rhs.small()) {
+            (Some(a), Some(b)) => a
+                .checked_mul(b)
+                .map_or_else(|| Self::from(BigInt::from(a) * b), Self::from),
+            _ => Self::from(self.big() * rhs.big()),
+        }
+    }
+}
+
+impl Sum for Answer {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::Unsigned(0), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use super::Answer;
+
+    #[test]
+    fn test_representation() {
+        assert_eq!(Answer::from(5i32), Answer::Unsigned(5));
+        assert_eq!(Answer::from(-5i64), Answer::Signed(-5));
+        assert_eq!(Answer::from(BigInt::from(7)), Answer::from(7u8));
+        assert_eq!("-12".parse::<Answer>(), Ok(Answer::Signed(-12)));
+        assert_eq!("AB,C".parse::<Answer>(), Ok(Answer::from("AB,C")));
+
+        let big = "123456789012345678901234567890".parse::<Answer>().unwrap();
+        assert!(matches!(big, Answer::Big(_)));
+        assert_eq!(big.to_string(), "123456789012345678901234567890");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let max = Answer::from(u64::MAX);
+        let sum = max.clone() + Answer::from(1u8);
+        assert_eq!(sum.to_string(), "18446744073709551616");
+        assert_eq!(sum + Answer::from(-1i8), max);
+
+        let product = max.clone() * max.clone();
+        assert_eq!(
+            product.to_string(),
+            "340282366920938463426481119284349108225"
+        );
+        assert_eq!(Answer::from(-3i8) * Answer::from(4u8), Answer::Signed(-12));
+
+        let sum = [1u64, 2, 3].into_iter().map(Answer::from).sum::<Answer>();
+        assert_eq!(sum, Answer::Unsigned(6));
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(
+            Answer::from(4u8).lcm(&Answer::from(6u8)),
+            Answer::Unsigned(12)
+        );
+        // the product of the primes doesn't even fit into an i128
+        let primes = [
+            1_000_000_007u64,
+            998_244_353,
+            1_000_000_009,
+            1_000_000_021,
+            1_000_000_033,
+        ];
+        let lcm = primes
+            .into_iter()
+            .map(Answer::from)
+            .reduce(|a, b| a.lcm(&b))
+            .unwrap();
+        assert_eq!(
+            lcm.to_string(),
+            "998244422877106327155866324560718552350207627"
+        );
+    }
+}
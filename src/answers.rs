@@ -0,0 +1,382 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Write},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use argon2::{Algorithm, Argon2, Params, Version};
+
+use crate::puzzle::Puzzle;
+
+/// Directory the known answers are stored in, relative to the working directory.
+pub const ANSWER_DIR: &str = "answers";
+
+const SALT_LEN: usize = 16;
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+fn from_hex<const N: usize>(hex: &str) -> Option<[u8; N]> {
+    if hex.len() != N * 2 || !hex.is_ascii() {
+        return None;
+    }
+    let mut bytes = [0; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// Salted Argon2id hash of an answer or puzzle input, leading and trailing whitespace is
+/// ignored. Answers are small numbers that a fast hash gives away by trying them all, so
+/// the hash is deliberately slow. Written as the cost `m=<KiB>,t=<passes>,p=<lanes>`, the
+/// hex encoded salt and the hash separated by spaces, the cost is kept so stored hashes
+/// still match after the default changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaltedHash {
+    params: Params,
+    salt: [u8; SALT_LEN],
+    hash: [u8; 32],
+}
+
+impl SaltedHash {
+    /// Hashes the value with a new random salt.
+    ///
+    /// # Errors
+    ///
+    /// If the system random number generator fails.
+    pub fn new(value: &str) -> anyhow::Result<Self> {
+        let mut salt = [0; SALT_LEN];
+        getrandom::getrandom(&mut salt)?;
+        Ok(Self::with_salt(salt, value))
+    }
+
+    /// Hashes the value with the salt and the default cost.
+    #[must_use]
+    pub fn with_salt(salt: [u8; SALT_LEN], value: &str) -> Self {
+        let params = Params::DEFAULT;
+        let hash = Self::derive(&params, &salt, value);
+        Self { params, salt, hash }
+    }
+
+    fn derive(params: &Params, salt: &[u8; SALT_LEN], value: &str) -> [u8; 32] {
+        let mut hash = [0; 32];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params.clone())
+            .hash_password_into(value.trim().as_bytes(), salt, &mut hash)
+            // the parameters are validated and the salt and output lengths fixed
+            .expect("Argon2 Error!");
+        hash
+    }
+
+    /// Whether this is the hash of the value.
+    #[must_use]
+    pub fn matches(&self, value: &str) -> bool {
+        self.hash == Self::derive(&self.params, &self.salt, value)
+    }
+}
+
+impl fmt::Display for SaltedHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "m={},t={},p={} {} {}",
+            self.params.m_cost(),
+            self.params.t_cost(),
+            self.params.p_cost(),
+            to_hex(&self.salt),
+            to_hex(&self.hash)
+        )
+    }
+}
+
+impl FromStr for SaltedHash {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.trim().split(' ');
+        let (Some(params), Some(salt), Some(hash), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(anyhow::anyhow!("Invalid Salted Hash!"));
+        };
+
+        let invalid_cost = || anyhow::anyhow!("Invalid Cost!");
+        let mut costs = params.split(',').map(|cost| cost.split_once('='));
+        let mut cost = |name: &str| {
+            costs
+                .next()
+                .flatten()
+                .filter(|(key, _)| *key == name)
+                .and_then(|(_, cost)| str::parse::<u32>(cost).ok())
+                .ok_or_else(invalid_cost)
+        };
+        let (m, t, p) = (cost("m")?, cost("t")?, cost("p")?);
+        if costs.next().is_some() {
+            return Err(invalid_cost());
+        }
+
+        Ok(Self {
+            params: Params::new(m, t, p, None).map_err(|_| invalid_cost())?,
+            salt: from_hex(salt).ok_or(anyhow::anyhow!("Invalid Salt!"))?,
+            hash: from_hex(hash).ok_or(anyhow::anyhow!("Invalid Hash!"))?,
+        })
+    }
+}
+
+/// Outcome of checking an answer or input against the answer store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    /// Nothing is known about the puzzle yet
+    Unknown,
+}
+
+impl Verdict {
+    fn of(hash: Option<&SaltedHash>, value: &str) -> Self {
+        match hash {
+            Some(hash) if hash.matches(value) => Self::Correct,
+            Some(_) => Self::Wrong,
+            None => Self::Unknown,
+        }
+    }
+}
+
+/// Salted hashes of the known correct answers and of the inputs they were found for, so
+/// neither is readable from the store. Stored in one file per year `<year>.txt` with a
+/// `<day> <part> <cost> <salt> <hash>` line per answer and `<day> input <cost> <salt> <hash>`
+/// per input.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AnswerStore {
+    answers: BTreeMap<Puzzle, SaltedHash>,
+    inputs: BTreeMap<(u16, u8), SaltedHash>,
+}
+
+impl AnswerStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn year_path(dir: &Path, year: u16) -> PathBuf {
+        dir.join(format!("{year}.txt"))
+    }
+
+    /// Loads the hashes of all years in the directory, a missing directory has no answers.
+    ///
+    /// # Errors
+    ///
+    /// On I/O errors or invalid lines.
+    pub fn load(dir: &Path) -> anyhow::Result<Self> {
+        let mut store = Self::new();
+        if !dir.exists() {
+            return Ok(store);
+        }
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let Some(year) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(".txt"))
+                .and_then(|year| str::parse::<u16>(year).ok())
+            else {
+                continue;
+            };
+
+            for (number, line) in fs::read_to_string(&path)?.lines().enumerate() {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                let invalid =
+                    || anyhow::anyhow!("{}:{}: Invalid Line!", path.display(), number + 1);
+                let mut fields = line.splitn(3, ' ');
+                let (Some(day), Some(part), Some(hash)) =
+                    (fields.next(), fields.next(), fields.next())
+                else {
+                    return Err(invalid());
+                };
+                let day = str::parse::<u8>(day).map_err(|_| invalid())?;
+                let hash = str::parse::<SaltedHash>(hash).map_err(|_| invalid())?;
+                if part == "input" {
+                    store.inputs.insert((year, day), hash);
+                } else {
+                    let part = str::parse::<u8>(part).map_err(|_| invalid())?;
+                    store.answers.insert(Puzzle::new(year, day, part), hash);
+                }
+            }
+        }
+
+        Ok(store)
+    }
+
+    /// Writes the hashes to the directory, one file per year.
+    ///
+    /// # Errors
+    ///
+    /// On I/O errors.
+    pub fn save(&self, dir: &Path) -> anyhow::Result<()> {
+        fs::create_dir_all(dir)?;
+
+        let days = self
+            .inputs
+            .keys()
+            .copied()
+            .chain(self.answers.keys().map(|puzzle| (puzzle.year, puzzle.day)))
+            .collect::<BTreeSet<_>>();
+        let mut years: BTreeMap<u16, String> = BTreeMap::new();
+        for (year, day) in days {
+            let contents = years.entry(year).or_default();
+            if let Some(hash) = self.inputs.get(&(year, day)) {
+                writeln!(contents, "{day} input {hash}")?;
+            }
+            let parts = self
+                .answers
+                .range(Puzzle::new(year, day, 0)..=Puzzle::new(year, day, u8::MAX));
+            for (puzzle, hash) in parts {
+                writeln!(contents, "{day} {} {hash}", puzzle.part)?;
+            }
+        }
+        for (year, contents) in years {
+            fs::write(Self::year_path(dir, year), contents)?;
+        }
+        Ok(())
+    }
+
+    /// Whether an answer of the puzzle is known.
+    #[must_use]
+    pub fn contains(&self, puzzle: Puzzle) -> bool {
+        self.answers.contains_key(&puzzle)
+    }
+
+    /// Stores the hash of the correct answer of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// If no salt can be generated.
+    pub fn insert(&mut self, puzzle: Puzzle, answer: &str) -> anyhow::Result<()> {
+        self.answers.insert(puzzle, SaltedHash::new(answer)?);
+        Ok(())
+    }
+
+    /// Stores the hash of the input the answers of the day are for.
+    ///
+    /// # Errors
+    ///
+    /// If no salt can be generated.
+    pub fn insert_input(&mut self, year: u16, day: u8, input: &str) -> anyhow::Result<()> {
+        self.inputs.insert((year, day), SaltedHash::new(input)?);
+        Ok(())
+    }
+
+    /// Checks the answer against the hash of the known correct one.
+    #[must_use]
+    pub fn verify(&self, puzzle: Puzzle, answer: &str) -> Verdict {
+        Verdict::of(self.answers.get(&puzzle), answer)
+    }
+
+    /// Checks that the input is the one the answers of the day are for.
+    #[must_use]
+    pub fn verify_input(&self, year: u16, day: u8, input: &str) -> Verdict {
+        Verdict::of(self.inputs.get(&(year, day)), input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use argon2::Params;
+
+    use super::{AnswerStore, SaltedHash, Verdict};
+    use crate::puzzle::Puzzle;
+
+    #[test]
+    fn test_salted_hash() {
+        let hash = SaltedHash::with_salt([0; 16], "142");
+        assert_eq!(
+            hash.to_string(),
+            "m=19456,t=2,p=1 00000000000000000000000000000000 \
+             9d339720579462c3ae30dab9795d5cda338890e933f6893c3aadfd4aec31e73c"
+        );
+        assert_eq!(str::parse::<SaltedHash>(&hash.to_string()).unwrap(), hash);
+        assert!(hash.matches("142\n"));
+        assert!(!hash.matches("141"));
+
+        // the same answer is hashed differently with every salt
+        let (a, b) = (
+            SaltedHash::new("142").unwrap(),
+            SaltedHash::new("142").unwrap(),
+        );
+        assert_ne!(a, b);
+        assert!(a.matches("142") && b.matches("142"));
+
+        // the cost is stored with the hash and used to check it instead of the default
+        let cheap = Params::new(8, 1, 1, None).unwrap();
+        let cheap = SaltedHash {
+            hash: SaltedHash::derive(&cheap, &[0; 16], "142"),
+            params: cheap,
+            salt: [0; 16],
+        };
+        let parsed = str::parse::<SaltedHash>(&cheap.to_string()).unwrap();
+        assert_eq!(parsed, cheap);
+        assert!(parsed.to_string().starts_with("m=8,t=1,p=1 "));
+        assert!(parsed.matches("142") && !parsed.matches("141"));
+
+        // the old format without cost, too little memory and the costs out of order
+        let (salt, hash) = ("0".repeat(32), "0".repeat(64));
+        for cost in ["", "m=1,t=2,p=1 ", "t=2,m=19456,p=1 ", "m=19456,t=2 "] {
+            assert!(str::parse::<SaltedHash>(&format!("{cost}{salt} {hash}")).is_err());
+        }
+        assert!(str::parse::<SaltedHash>(&format!("m=8,t=1,p=1 {salt} {hash}")).is_ok());
+        assert!(str::parse::<SaltedHash>("00 11").is_err());
+        assert!(str::parse::<SaltedHash>("zz").is_err());
+    }
+
+    #[test]
+    fn test_load_and_save() {
+        let dir = std::env::temp_dir().join(format!("answers-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(AnswerStore::load(&dir).unwrap(), AnswerStore::new());
+
+        let mut store = AnswerStore::new();
+        store.insert(Puzzle::new(2023, 1, 1), "142").unwrap();
+        store.insert(Puzzle::new(2023, 10, 2), "8").unwrap();
+        store.insert(Puzzle::new(2015, 1, 1), "-3").unwrap();
+        store.insert_input(2023, 1, "1abc2\n").unwrap();
+        store.save(&dir).unwrap();
+
+        let contents = fs::read_to_string(dir.join("2023.txt")).unwrap();
+        let lines = contents
+            .lines()
+            .map(|line| line.splitn(3, ' ').take(2).collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>();
+        assert_eq!(lines, ["1 input", "1 1", "10 2"]);
+        assert_eq!(AnswerStore::load(&dir).unwrap(), store);
+
+        assert_eq!(
+            store.verify(Puzzle::new(2023, 1, 1), "142"),
+            Verdict::Correct
+        );
+        assert_eq!(store.verify(Puzzle::new(2023, 1, 1), "141"), Verdict::Wrong);
+        assert_eq!(
+            store.verify(Puzzle::new(2023, 1, 2), "281"),
+            Verdict::Unknown
+        );
+        assert_eq!(store.verify_input(2023, 1, "1abc2"), Verdict::Correct);
+        assert_eq!(store.verify_input(2023, 1, "1abc3"), Verdict::Wrong);
+        assert_eq!(store.verify_input(2023, 2, "1abc2"), Verdict::Unknown);
+
+        fs::write(dir.join("2016.txt"), "# day part salt hash\n1 1 142\n").unwrap();
+        assert!(AnswerStore::load(&dir).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,558 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    answers::{AnswerStore, Verdict, ANSWER_DIR},
+    examples::{examples_dir, load_examples},
+    input::{read_cached_input, INPUT_DIR, REPL_FLAG},
+    puzzle::{validate_day, Puzzle, DEFAULT_YEAR},
+    report::{self, Entry, Report, SortKey},
+    runner, scaffold, watch,
+};
+use clap::{Parser, Subcommand};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process, thread,
+    time::Duration,
+};
+
+/// Tooling around the daily puzzle solutions.
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List the puzzles with a solution
+    Days {
+        /// Only list the puzzles of the year
+        #[arg(long)]
+        year: Option<u16>,
+    },
+    /// Parse the input of a day and explore it with the commands of the day
+    Repl {
+        day: u8,
+        /// Input file, defaults to the input of the day
+        input: Option<PathBuf>,
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        /// Part whose model is explored
+        #[arg(long, default_value_t = 1)]
+        part: u8,
+    },
+    /// Run the solution of a day and print its answer, passing the arguments after `--` to
+    /// it, like `--overlap=leftmost-first` to day 1 part 2
+    Run {
+        day: u8,
+        /// Input file, defaults to the input of the day
+        input: Option<PathBuf>,
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        #[arg(long, default_value_t = 1)]
+        part: u8,
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// Run the solutions on their inputs and check the answers against the answer store
+    Verify {
+        #[arg(long)]
+        year: Option<u16>,
+        #[arg(long)]
+        day: Option<u8>,
+        /// Store the answers of puzzles without a known answer
+        #[arg(long)]
+        record: bool,
+    },
+    /// Run all solutions and write a Markdown and HTML report of their status and performance
+    Report {
+        #[arg(long)]
+        year: Option<u16>,
+        /// Runs of each solution to take the median runtime of
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+        /// Order of the rows: puzzle, runtime, memory or lines
+        #[arg(long, default_value = "puzzle")]
+        sort: SortKey,
+        #[arg(long, default_value = "REPORT.md")]
+        markdown: PathBuf,
+        #[arg(long, default_value = "REPORT.html")]
+        html: PathBuf,
+    },
+    /// Encrypt or decrypt the cached inputs with the passphrase from `AOC_PASSPHRASE`
+    #[cfg(feature = "encrypt")]
+    Inputs {
+        #[command(subcommand)]
+        command: InputsCommand,
+    },
+    /// Print stars, local scores, part 2 times and ranks of a private leaderboard
+    #[cfg(feature = "leaderboard")]
+    Leaderboard {
+        /// Exported leaderboard JSON, fetched with the session cookie from `AOC_SESSION` if
+        /// not given
+        file: Option<PathBuf>,
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        /// Id of the private leaderboard to fetch
+        #[arg(long)]
+        id: Option<u64>,
+        /// Endpoint to fetch the JSON from instead of the one of the leaderboard id
+        #[arg(long)]
+        url: Option<String>,
+    },
+    /// Rerun the tests, examples and solutions of a day whenever its sources, input or
+    /// examples change
+    Watch {
+        day: u8,
+        #[arg(long, default_value_t = DEFAULT_YEAR)]
+        year: u16,
+        /// Milliseconds between checks for changed files
+        #[arg(long, default_value_t = 500)]
+        interval_ms: u64,
+    },
+    /// Create the solutions of a new day from the template
+    New { year: u16, day: u8 },
+    /// Serve the solutions over HTTP
+    #[cfg(feature = "serve")]
+    Serve(ServeArgs),
+}
+
+#[cfg(feature = "serve")]
+#[derive(Debug, clap::Args)]
+struct ServeArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8023")]
+    addr: String,
+    /// Milliseconds a solution may run before it is killed
+    #[arg(long, default_value_t = 10_000)]
+    time_budget_ms: u64,
+    /// Largest puzzle input accepted in bytes
+    #[arg(long, default_value_t = 1024 * 1024)]
+    max_input_size: usize,
+    /// Most requests answered at once, more are rejected as busy
+    #[arg(long, default_value_t = 8)]
+    max_concurrent: usize,
+}
+
+#[cfg(feature = "encrypt")]
+#[derive(Debug, Subcommand)]
+enum InputsCommand {
+    /// Write an encrypted copy next to every plain input, to be committed instead of it
+    Encrypt {
+        #[arg(long)]
+        year: Option<u16>,
+    },
+    /// Restore the plain inputs from their encrypted copies
+    Decrypt {
+        #[arg(long)]
+        year: Option<u16>,
+    },
+}
+
+/// Encrypts or decrypts all cached inputs of the selected year.
+#[cfg(feature = "encrypt")]
+fn crypt_inputs(command: &InputsCommand) -> anyhow::Result<()> {
+    use adventofcode::{
+        crypt,
+        input::{encrypted_path, input_path},
+        puzzle::DAYS,
+    };
+
+    let passphrase = crypt::passphrase()?;
+    let (year, encrypt) = match *command {
+        InputsCommand::Encrypt { year } => (year, true),
+        InputsCommand::Decrypt { year } => (year, false),
+    };
+    let dir = Path::new(INPUT_DIR);
+
+    let mut years = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u16>().ok())
+        .filter(|found| year.is_none_or(|year| *found == year))
+        .collect::<Vec<_>>();
+    years.sort_unstable();
+
+    for year in years {
+        for day in DAYS {
+            let path = input_path(dir, year, day);
+            let encrypted = encrypted_path(&path);
+            if encrypt && path.exists() {
+                let input = fs::read(&path)?;
+                // a new salt changes the whole file, only rewrite it when the input changed
+                let unchanged = fs::read(&encrypted)
+                    .ok()
+                    .and_then(|data| crypt::decrypt(&data, &passphrase).ok())
+                    .is_some_and(|decrypted| decrypted == input);
+                if !unchanged {
+                    fs::write(&encrypted, crypt::encrypt(&input, &passphrase)?)?;
+                    println!("Encrypted {}", encrypted.display());
+                }
+            } else if !encrypt && encrypted.exists() {
+                fs::write(&path, crypt::decrypt(&fs::read(&encrypted)?, &passphrase)?)?;
+                println!("Decrypted {}", path.display());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads the leaderboard JSON from the file or fetches it from the endpoint.
+#[cfg(feature = "leaderboard")]
+fn leaderboard_json(
+    file: Option<PathBuf>,
+    year: u16,
+    id: Option<u64>,
+    url: Option<String>,
+) -> anyhow::Result<String> {
+    use adventofcode::leaderboard::{fetch, leaderboard_url, SESSION_ENV};
+
+    if let Some(file) = file {
+        return Ok(fs::read_to_string(file)?);
+    }
+    let Some(url) = url.or_else(|| id.map(|id| leaderboard_url(year, id))) else {
+        return Err(anyhow::anyhow!("Leaderboard File, Id or Url required!"));
+    };
+    let session = std::env::var(SESSION_ENV)
+        .map_err(|_| anyhow::anyhow!("Session cookie required in {SESSION_ENV}!"))?;
+    fetch(&url, &session)
+}
+
+/// Runs cargo with the profile the running binary was built with, so the solutions are
+/// rebuilt where they are run from.
+fn cargo(bin_dir: &Path, command: &str, args: &[String]) -> anyhow::Result<bool> {
+    let mut cargo = process::Command::new(std::env::var_os("CARGO").unwrap_or("cargo".into()));
+    cargo.args([command, "--quiet"]);
+    if bin_dir
+        .file_name()
+        .is_some_and(|profile| profile == "release")
+    {
+        cargo.arg("--release");
+    }
+    cargo.args(args);
+    Ok(cargo.status()?.success())
+}
+
+/// Tests, builds and runs the solutions of the day on its examples and input, comparing
+/// each answer with the previous one of the part.
+fn rerun(
+    bin_dir: &Path,
+    year: u16,
+    day: u8,
+    previous: &mut BTreeMap<u8, String>,
+) -> anyhow::Result<()> {
+    let puzzles = (1..=2)
+        .map(|part| Puzzle::new(year, day, part))
+        .filter(|puzzle| scaffold::source_path(*puzzle).exists())
+        .collect::<Vec<_>>();
+    if puzzles.is_empty() {
+        return Err(anyhow::anyhow!("No Solution for {year} Day {day}!"));
+    }
+
+    let bins = puzzles
+        .iter()
+        .flat_map(|puzzle| ["--bin".to_string(), puzzle.bin_name()])
+        .collect::<Vec<_>>();
+    // the solvers are tested in the library, the binaries only test their own output
+    let solvers_tested = cargo(
+        bin_dir,
+        "test",
+        &["--lib".to_string(), format!("year{year}::day{day}::")],
+    )?;
+    let bins_tested = cargo(bin_dir, "test", &bins)?;
+    if !(solvers_tested && bins_tested) {
+        println!("tests FAILED");
+    }
+    if !cargo(bin_dir, "build", &bins)? {
+        return Err(anyhow::anyhow!("Build failed!"));
+    }
+
+    let examples = load_examples(&examples_dir())?;
+    for example in examples
+        .iter()
+        .filter(|example| (example.year, example.day) == (year, day))
+    {
+        let name = example
+            .path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy();
+        for (&part, expected) in &example.answers {
+            let puzzle = Puzzle::new(year, day, part);
+            if !puzzles.contains(&puzzle) {
+                continue;
+            }
+            match runner::run_solution(bin_dir, puzzle, &example.input) {
+                Ok(answer) if answer == *expected => println!("{name} part {part}: {answer} ok"),
+                Ok(answer) => println!("{name} part {part}: {answer} EXPECTED {expected}"),
+                Err(err) => println!("{name} part {part}: error: {err}"),
+            }
+        }
+    }
+
+    let input = read_cached_input(Path::new(INPUT_DIR), year, day)?;
+    let store = AnswerStore::load(Path::new(ANSWER_DIR))?;
+    for puzzle in puzzles {
+        match runner::run_solution_timed(bin_dir, puzzle, &input, None) {
+            Ok(run) => {
+                let verdict = store.verify(puzzle, &run.answer);
+                let comparison = watch::compare(
+                    &run.answer,
+                    previous.get(&puzzle.part).map(String::as_str),
+                    verdict,
+                );
+                println!("{puzzle}: {comparison} ({:?})", run.elapsed);
+                previous.insert(puzzle.part, run.answer);
+            }
+            Err(err) => println!("{puzzle}: error: {err}"),
+        }
+    }
+    Ok(())
+}
+
+/// Runs the solution on the input file or the cached input and prints its answer.
+fn run(
+    bin_dir: &Path,
+    puzzle: Puzzle,
+    input: Option<&Path>,
+    args: &[String],
+) -> anyhow::Result<()> {
+    validate_day(puzzle.year, puzzle.day)?;
+    let input = match input {
+        Some(path) => fs::read_to_string(path)?,
+        None => read_cached_input(Path::new(INPUT_DIR), puzzle.year, puzzle.day)?,
+    };
+    let run = runner::run_solution_with_args(bin_dir, puzzle, &input, args, None)?;
+    println!("{puzzle}: {} ({:?})", run.answer, run.elapsed);
+    Ok(())
+}
+
+/// Reruns the day whenever one of its files changes, until interrupted.
+fn watch(bin_dir: &Path, year: u16, day: u8, interval: Duration) -> anyhow::Result<()> {
+    validate_day(year, day)?;
+    let mut previous = BTreeMap::new();
+    let mut last: Option<watch::Stamps> = None;
+
+    loop {
+        let stamps = watch::stamps(&watch::watched_files(
+            Path::new("."),
+            &examples_dir(),
+            year,
+            day,
+        )?);
+        if let Some(last) = &last {
+            let changed = watch::changed(last, &stamps);
+            if changed.is_empty() {
+                thread::sleep(interval);
+                continue;
+            }
+            for file in changed {
+                println!("\n{} changed", file.display());
+            }
+        }
+        last = Some(stamps);
+
+        if let Err(err) = rerun(bin_dir, year, day, &mut previous) {
+            println!("error: {err}");
+        }
+        println!("watching {year} day {day} for changes...");
+    }
+}
+
+/// Runs the selected solutions on their inputs and prints the verdict of each answer,
+/// returns the number of wrong answers and failed solutions.
+fn verify(bin_dir: &Path, puzzles: &[Puzzle], record: bool) -> anyhow::Result<usize> {
+    let mut store = AnswerStore::load(Path::new(ANSWER_DIR))?;
+    let mut failures = 0;
+
+    for &puzzle in puzzles {
+        let input = match read_cached_input(Path::new(INPUT_DIR), puzzle.year, puzzle.day) {
+            Ok(input) => input,
+            Err(err) => {
+                failures += 1;
+                println!("{puzzle}: error: {err}");
+                continue;
+            }
+        };
+
+        // answers only verify against the input they were recorded for
+        match store.verify_input(puzzle.year, puzzle.day, &input) {
+            Verdict::Wrong => {
+                failures += 1;
+                println!("{puzzle}: input differs from the one the answers are for");
+                continue;
+            }
+            Verdict::Unknown if record => store.insert_input(puzzle.year, puzzle.day, &input)?,
+            Verdict::Correct | Verdict::Unknown => {}
+        }
+
+        let run = match runner::run_solution_timed(bin_dir, puzzle, &input, None) {
+            Ok(run) => run,
+            Err(err) => {
+                failures += 1;
+                println!("{puzzle}: error: {err}");
+                continue;
+            }
+        };
+
+        let verdict = match store.verify(puzzle, &run.answer) {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => {
+                failures += 1;
+                "WRONG"
+            }
+            Verdict::Unknown if record => {
+                store.insert(puzzle, &run.answer)?;
+                "recorded"
+            }
+            Verdict::Unknown => "unverified",
+        };
+        println!("{puzzle}: {} {verdict} ({:?})", run.answer, run.elapsed);
+    }
+
+    if record {
+        store.save(Path::new(ANSWER_DIR))?;
+    }
+    Ok(failures)
+}
+
+/// Measures the solutions on their inputs for the report.
+fn report_entries(bin_dir: &Path, puzzles: &[Puzzle], runs: usize) -> anyhow::Result<Vec<Entry>> {
+    let store = AnswerStore::load(Path::new(ANSWER_DIR))?;
+    let mut entries = Vec::new();
+
+    for &puzzle in puzzles {
+        let measurement = read_cached_input(Path::new(INPUT_DIR), puzzle.year, puzzle.day)
+            .and_then(|input| report::measure(bin_dir, &store, puzzle, &input, runs))
+            .map_err(|err| err.to_string());
+        let lines_of_code = fs::read_to_string(scaffold::solver_path(puzzle))
+            .ok()
+            .map(|source| report::lines_of_code(&source));
+        println!(
+            "{puzzle}: {}",
+            if measurement.is_ok() { "ok" } else { "failed" }
+        );
+        entries.push(Entry {
+            puzzle,
+            measurement,
+            lines_of_code,
+        });
+    }
+    Ok(entries)
+}
+
+/// Serves the solutions over HTTP until interrupted.
+#[cfg(feature = "serve")]
+fn serve(bin_dir: PathBuf, args: ServeArgs) -> anyhow::Result<()> {
+    use adventofcode::serve::{serve, ServeConfig};
+
+    let addr = args.addr;
+    let server = tiny_http::Server::http(&addr)
+        .map_err(|err| anyhow::anyhow!("Error listening on {addr}: {err}"))?;
+    println!("Listening on http://{}", server.server_addr());
+    serve(
+        &server,
+        ServeConfig {
+            bin_dir,
+            time_budget: std::time::Duration::from_millis(args.time_budget_ms),
+            max_input_size: args.max_input_size,
+            max_concurrent: args.max_concurrent,
+        },
+    );
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let bin_dir = runner::bin_dir()?;
+
+    match args.command {
+        Command::Days { year } => {
+            for puzzle in runner::solutions(&bin_dir)? {
+                if year.is_none_or(|year| puzzle.year == year) {
+                    println!("{puzzle}");
+                }
+            }
+        }
+        Command::Repl {
+            day,
+            input,
+            year,
+            part,
+        } => {
+            let status =
+                process::Command::new(runner::bin_path(&bin_dir, Puzzle::new(year, day, part)))
+                    .arg(REPL_FLAG)
+                    .args(input)
+                    .status()?;
+            process::exit(status.code().unwrap_or(1));
+        }
+        Command::Run {
+            day,
+            input,
+            year,
+            part,
+            args,
+        } => run(
+            &bin_dir,
+            Puzzle::new(year, day, part),
+            input.as_deref(),
+            &args,
+        )?,
+        Command::Verify { year, day, record } => {
+            let puzzles = runner::solutions(&bin_dir)?
+                .into_iter()
+                .filter(|puzzle| year.is_none_or(|year| puzzle.year == year))
+                .filter(|puzzle| day.is_none_or(|day| puzzle.day == day))
+                .collect::<Vec<_>>();
+            let failures = verify(&bin_dir, &puzzles, record)?;
+            if failures > 0 {
+                return Err(anyhow::anyhow!("{failures} of {} failed!", puzzles.len()));
+            }
+        }
+        Command::Report {
+            year,
+            runs,
+            sort,
+            markdown,
+            html,
+        } => {
+            let puzzles = runner::solutions(&bin_dir)?
+                .into_iter()
+                .filter(|puzzle| year.is_none_or(|year| puzzle.year == year))
+                .collect::<Vec<_>>();
+            let report = Report::new(report_entries(&bin_dir, &puzzles, runs)?, sort);
+            fs::write(&markdown, report.to_markdown())?;
+            fs::write(&html, report.to_html())?;
+            println!("Wrote {} and {}", markdown.display(), html.display());
+        }
+        #[cfg(feature = "encrypt")]
+        Command::Inputs { command } => crypt_inputs(&command)?,
+        #[cfg(feature = "leaderboard")]
+        Command::Leaderboard {
+            file,
+            year,
+            id,
+            url,
+        } => {
+            let json = leaderboard_json(file, year, id, url)?;
+            print!(
+                "{}",
+                adventofcode::leaderboard::Leaderboard::parse(&json)?.to_text()
+            );
+        }
+        Command::Watch {
+            day,
+            year,
+            interval_ms,
+        } => watch(&bin_dir, year, day, Duration::from_millis(interval_ms))?,
+        Command::New { year, day } => {
+            for path in scaffold::scaffold_day(Path::new("."), year, day)? {
+                println!("Created {}", path.display());
+            }
+        }
+        #[cfg(feature = "serve")]
+        Command::Serve(args) => serve(bin_dir, args)?,
+    }
+
+    Ok(())
+}
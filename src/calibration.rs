@@ -0,0 +1,471 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{answer::Answer, vocabulary::Vocabulary};
+
+/// Bytes read at a time by [`Calibration::sum_reader`].
+#[cfg(feature = "std")]
+pub const CHUNK_SIZE: usize = 1 << 20;
+
+/// Each byte repeated in all bytes of a word.
+const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Bytes of the word that are ASCII digits flagged in their high bit. Only the lowest
+/// flagged byte is exact, a digit may flag the byte above it as well.
+fn digit_bits(word: u64) -> u64 {
+    let offsets = word ^ (LOW_BITS * u64::from(b'0'));
+    offsets.wrapping_sub(LOW_BITS * 10) & !offsets & HIGH_BITS
+}
+
+/// Index of the first ASCII digit, eight bytes at a time.
+fn find_digit(bytes: &[u8]) -> Option<usize> {
+    let mut chunks = bytes.chunks_exact(8);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        let word = u64::from_le_bytes(chunk.try_into().ok()?);
+        let bits = digit_bits(word);
+        if bits != 0 {
+            return Some(offset + bits.trailing_zeros() as usize / 8);
+        }
+        offset += 8;
+    }
+    chunks
+        .remainder()
+        .iter()
+        .position(u8::is_ascii_digit)
+        .map(|index| offset + index)
+}
+
+/// Index of the last ASCII digit, eight bytes at a time.
+fn rfind_digit(bytes: &[u8]) -> Option<usize> {
+    let mut chunks = bytes.rchunks_exact(8);
+    let mut end = bytes.len();
+    for chunk in &mut chunks {
+        let word = u64::from_le_bytes(chunk.try_into().ok()?);
+        if digit_bits(word) != 0 {
+            // there is a digit, but the highest flagged byte may not be one
+            let index = chunk.iter().rposition(u8::is_ascii_digit)?;
+            return Some(end - 8 + index);
+        }
+        end -= 8;
+    }
+    chunks.remainder().iter().rposition(u8::is_ascii_digit)
+}
+
+/// Node of a [`ByteTrie`].
+#[derive(Debug, Clone, Copy, Default)]
+struct Node {
+    /// Index of the first edge of the node, the edges of a node being consecutive
+    first_edge: u32,
+    edge_count: u16,
+    /// Value of the word ending in the node
+    value: Option<u8>,
+}
+
+/// Trie of words with all edges in one array, and a table of the children of the root.
+#[derive(Debug, Clone)]
+struct ByteTrie {
+    nodes: Vec<Node>,
+    /// Byte and child node of the edges, sorted by byte for each node
+    edges: Vec<(u8, u32)>,
+    /// Child of the root for each byte, 0 (the root) if there is none
+    root: [u32; 256],
+}
+
+impl ByteTrie {
+    /// Trie of the words, `None` if it has too many nodes for the compact indices.
+    fn new<'w>(words: impl IntoIterator<Item = (&'w [u8], u8)>) -> Option<Self> {
+        let mut children = alloc::vec![BTreeMap::new()];
+        let mut values = alloc::vec![None];
+        for (word, value) in words {
+            let mut node = 0;
+            for &byte in word {
+                let next = children.len();
+                node = *children[node].entry(byte).or_insert(next);
+                if node == next {
+                    children.push(BTreeMap::new());
+                    values.push(None);
+                }
+            }
+            values[node] = Some(value);
+        }
+
+        let mut trie = Self {
+            nodes: Vec::with_capacity(children.len()),
+            edges: Vec::new(),
+            root: [0; 256],
+        };
+        for (node_children, value) in children.into_iter().zip(values) {
+            trie.nodes.push(Node {
+                first_edge: u32::try_from(trie.edges.len()).ok()?,
+                edge_count: u16::try_from(node_children.len()).ok()?,
+                value,
+            });
+            for (byte, child) in node_children {
+                trie.edges.push((byte, u32::try_from(child).ok()?));
+            }
+        }
+        for index in 0..usize::from(trie.nodes[0].edge_count) {
+            let (byte, child) = trie.edges[index];
+            trie.root[usize::from(byte)] = child;
+        }
+        Some(trie)
+    }
+
+    fn children(&self, node: u32) -> &[(u8, u32)] {
+        let node = self.nodes[node as usize];
+        let first = node.first_edge as usize;
+        &self.edges[first..first + usize::from(node.edge_count)]
+    }
+
+    /// Value of the longest word the bytes start with.
+    fn longest(&self, bytes: &[u8]) -> Option<u8> {
+        let (&first, rest) = bytes.split_first()?;
+        let mut node = self.root[usize::from(first)];
+        if node == 0 {
+            return None;
+        }
+        let mut longest = self.nodes[node as usize].value;
+        for &byte in rest {
+            let Some(&(_, child)) = self.children(node).iter().find(|(edge, _)| *edge == byte)
+            else {
+                break;
+            };
+            node = child;
+            longest = self.nodes[node as usize].value.or(longest);
+        }
+        longest
+    }
+}
+
+/// Fast path for the calibration values of day 1, the first and last number of each line
+/// joined, working on bytes.
+///
+/// The first number is the leftmost one. The last one is the rightmost, as long as no word
+/// can start inside another one before its last character, which is what the overlapping
+/// matches of [`Vocabulary::matches`] come down to for such words. Lines with other than
+/// ASCII characters are left to the [`Vocabulary`].
+#[derive(Debug, Clone)]
+pub struct Calibration {
+    trie: ByteTrie,
+    vocabulary: Vocabulary,
+}
+
+impl Calibration {
+    /// Fast path for the words, `None` unless they are ASCII without digits and none can
+    /// start inside another one before its last character, like `one` in `twenty-one`.
+    #[must_use]
+    pub fn new(words: &[(&str, u8)]) -> Option<Self> {
+        if words.iter().any(|(word, _)| {
+            word.is_empty() || !word.is_ascii() || word.contains(|c: char| c.is_ascii_digit())
+        }) {
+            return None;
+        }
+        for (word, _) in words {
+            for start in 1..word.len().saturating_sub(1) {
+                let tail = &word[start..];
+                if words
+                    .iter()
+                    .any(|(other, _)| tail.starts_with(other) || other.starts_with(tail))
+                {
+                    return None;
+                }
+            }
+        }
+
+        Some(Self {
+            trie: ByteTrie::new(words.iter().map(|(word, value)| (word.as_bytes(), *value)))?,
+            vocabulary: Vocabulary::new(words.iter().copied()),
+        })
+    }
+
+    /// First and last number of the line.
+    fn first_and_last(&self, line: &[u8]) -> anyhow::Result<Option<(u8, u8)>> {
+        if !line.is_ascii() {
+            let line = core::str::from_utf8(line).map_err(|_| anyhow::anyhow!("Invalid UTF-8!"))?;
+            let first = self.vocabulary.matches(line).next();
+            let last = self.vocabulary.last_match(line);
+            return Ok(first
+                .zip(last)
+                .map(|(first, last)| (first.value, last.value)));
+        }
+
+        // words don't contain digits, a word before the first digit ends before it
+        let first_digit = find_digit(line);
+        let words_end = first_digit.unwrap_or(line.len());
+        let Some(first) = (0..words_end)
+            .find_map(|start| self.trie.longest(&line[start..words_end]))
+            .or_else(|| first_digit.map(|index| line[index] - b'0'))
+        else {
+            return Ok(None);
+        };
+
+        let last_digit = rfind_digit(line);
+        let words_start = last_digit.map_or(0, |index| index + 1);
+        let last = (words_start..line.len())
+            .rev()
+            .find_map(|start| self.trie.longest(&line[start..]))
+            .or_else(|| last_digit.map(|index| line[index] - b'0'))
+            .unwrap_or(first);
+        Ok(Some((first, last)))
+    }
+
+    /// Calibration value of the line, its first and last number joined.
+    ///
+    /// # Errors
+    ///
+    /// If the line has no number or isn't valid UTF-8.
+    pub fn value(&self, line: &[u8]) -> anyhow::Result<u64> {
+        let (first, last) = self
+            .first_and_last(line)?
+            .ok_or_else(|| anyhow::anyhow!("Invalid Input, Lines without digit"))?;
+        let shift = if last >= 100 {
+            1000
+        } else if last >= 10 {
+            100
+        } else {
+            10
+        };
+        Ok(u64::from(first) * shift + u64::from(last))
+    }
+
+    /// Adds the calibration values of the lines to the sum, counting the lines for errors.
+    fn add_lines(
+        &self,
+        bytes: &[u8],
+        sum: &mut u128,
+        line_number: &mut usize,
+    ) -> anyhow::Result<()> {
+        let mut start = 0;
+        for end in memchr::memchr_iter(b'\n', bytes).chain(
+            // the last line unless it is terminated
+            Some(bytes.len()).filter(|&len| len > 0 && bytes[len - 1] != b'\n'),
+        ) {
+            *line_number += 1;
+            let value = self
+                .value(&bytes[start..end])
+                .map_err(|err| anyhow::anyhow!("Line {line_number}: {err}"))?;
+            *sum += u128::from(value);
+            start = end + 1;
+        }
+        Ok(())
+    }
+
+    /// Sum of the calibration values of the lines.
+    ///
+    /// # Errors
+    ///
+    /// If a line has no number or isn't valid UTF-8.
+    pub fn sum(&self, input: &[u8]) -> anyhow::Result<Answer> {
+        let mut sum = 0;
+        self.add_lines(input, &mut sum, &mut 0)?;
+        Ok(Answer::from(sum))
+    }
+
+    /// Like [`Calibration::sum`] but reads the input in chunks of about the size, so it
+    /// never has to fit into memory.
+    ///
+    /// # Errors
+    ///
+    /// If reading fails, a line has no number or isn't valid UTF-8.
+    #[cfg(feature = "std")]
+    pub fn sum_reader(
+        &self,
+        mut reader: impl std::io::Read,
+        chunk_size: usize,
+    ) -> anyhow::Result<Answer> {
+        let mut buffer = Vec::with_capacity(chunk_size);
+        let mut sum = 0;
+        let mut line_number = 0;
+        loop {
+            let len = buffer.len();
+            buffer.resize(len + chunk_size.max(1), 0);
+            let read = match reader.read(&mut buffer[len..]) {
+                Ok(read) => read,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {
+                    buffer.truncate(len);
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+            buffer.truncate(len + read);
+            if read == 0 && len == buffer.len() {
+                // end of input, the rest is the unterminated last line
+                self.add_lines(&buffer, &mut sum, &mut line_number)?;
+                return Ok(Answer::from(sum));
+            }
+
+            // complete lines only, the rest is kept for the next chunk
+            if let Some(end) = memchr::memrchr(b'\n', &buffer[len..]) {
+                let end = len + end + 1;
+                self.add_lines(&buffer[..end], &mut sum, &mut line_number)?;
+                buffer.drain(..end);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+
+    use proptest::{collection::vec, prelude::*, sample::select};
+
+    use super::{find_digit, rfind_digit, Calibration};
+    use crate::{
+        answer::Answer,
+        vocabulary::{compounds, Vocabulary, ENGLISH, ENGLISH_TENS, GERMAN},
+    };
+
+    /// The first and last number of each line joined, with the [`Vocabulary`].
+    fn reference(input: &str) -> Option<Answer> {
+        let vocabulary = Vocabulary::default();
+        input
+            .split_terminator('\n')
+            .map(|line| {
+                let first = vocabulary.matches(line).next()?;
+                let last = vocabulary.matches(line).last()?;
+                Some(Answer::from(
+                    format!("{}{}", first.value, last.value)
+                        .parse::<u64>()
+                        .ok()?,
+                ))
+            })
+            .sum()
+    }
+
+    fn puzzle() -> Calibration {
+        Calibration::new(&ENGLISH[1..]).unwrap()
+    }
+
+    #[test]
+    fn test_find_digit() {
+        for line in [
+            "",
+            "a",
+            "1",
+            "abcdefgh9",
+            "9abcdefgh",
+            "ab3cdefghijk4lmnopq",
+            "٣abcdefgh",
+        ] {
+            let bytes = line.as_bytes();
+            assert_eq!(
+                find_digit(bytes),
+                bytes.iter().position(u8::is_ascii_digit),
+                "{line}"
+            );
+            assert_eq!(
+                rfind_digit(bytes),
+                bytes.iter().rposition(u8::is_ascii_digit),
+                "{line}"
+            );
+        }
+        // a digit below may flag the byte above it
+        assert_eq!(rfind_digit(b"9:;<=>?/"), Some(0));
+    }
+
+    #[test]
+    fn test_value() {
+        let calibration = puzzle();
+        assert_eq!(calibration.value(b"two1nine").unwrap(), 29);
+        assert_eq!(calibration.value(b"eightwothree").unwrap(), 83);
+        assert_eq!(calibration.value(b"xtwone3four").unwrap(), 24);
+        assert_eq!(calibration.value(b"zoneight234").unwrap(), 14);
+        assert_eq!(calibration.value(b"7pqrstsixteen").unwrap(), 76);
+        assert_eq!(calibration.value(b"oneight").unwrap(), 18);
+        assert_eq!(calibration.value("ünë٣two".as_bytes()).unwrap(), 32);
+        assert!(calibration.value(b"zero").is_err());
+        assert!(calibration.value(b"\xffone").is_err());
+
+        let words = [("ten", 10), ("eleven", 11), ("one", 1)];
+        assert_eq!(
+            Calibration::new(&words)
+                .unwrap()
+                .value(b"teneleven")
+                .unwrap(),
+            1011
+        );
+    }
+
+    #[test]
+    fn test_eligible() {
+        let compounds = compounds(&ENGLISH_TENS, &ENGLISH[1..], "-");
+        let compounds = compounds
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+            .collect::<Vec<_>>();
+        assert!(Calibration::new(&compounds).is_none());
+        assert!(Calibration::new(&GERMAN).is_none());
+        assert!(Calibration::new(&[("4x", 4)]).is_none());
+        assert!(Calibration::new(&ENGLISH).is_some());
+    }
+
+    /// Reader failing once with [`std::io::ErrorKind::Interrupted`] after its first read.
+    struct InterruptedOnce<R> {
+        reader: R,
+        reads: usize,
+    }
+
+    impl<R: std::io::Read> std::io::Read for InterruptedOnce<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.reads += 1;
+            if self.reads == 2 {
+                return Err(std::io::ErrorKind::Interrupted.into());
+            }
+            self.reader.read(buf)
+        }
+    }
+
+    #[test]
+    fn test_sum_reader() {
+        let input = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
+        let calibration = puzzle();
+        for chunk_size in [1, 2, 7, 64, super::CHUNK_SIZE] {
+            let sum = calibration
+                .sum_reader(input.as_bytes(), chunk_size)
+                .unwrap();
+            assert_eq!(sum, Answer::from(281u16));
+        }
+        assert_eq!(
+            calibration.sum(input.as_bytes()).unwrap(),
+            Answer::from(281u16)
+        );
+
+        let err = calibration
+            .sum_reader("1\n\n2\n".as_bytes(), 3)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Line 2: Invalid Input, Lines without digit"
+        );
+
+        // an interrupted read is retried instead of ending the input
+        let mut reader = InterruptedOnce {
+            reader: input.as_bytes(),
+            reads: 0,
+        };
+        let sum = calibration.sum_reader(&mut reader, 16).unwrap();
+        assert_eq!(sum, Answer::from(281u16));
+        assert!(reader.reads > 2);
+    }
+
+    fn line_strategy() -> impl Strategy<Value = String> {
+        let pieces = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "zero", "on",
+            "tw", "thr", "eigh", "nin", "e", "o", "n", "t", "x", "ab", "1", "7", "٣", "７", "ü",
+        ];
+        vec(select(pieces.to_vec()), 1..12).prop_map(|pieces| pieces.concat())
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_reference(lines in vec(line_strategy(), 0..20), chunk_size in 1..64usize) {
+            let input = lines.join("\n");
+            let calibration = puzzle();
+            let expected = reference(&input);
+            prop_assert_eq!(calibration.sum(input.as_bytes()).ok(), expected.clone());
+            prop_assert_eq!(calibration.sum_reader(input.as_bytes(), chunk_size).ok(), expected);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use core::fmt;
+
+use num_traits::{checked_pow, pow, CheckedAdd, CheckedMul, CheckedSub, One, Zero};
+
+/// Integer overflow in an operation of the solution of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub day: u8,
+    pub operation: &'static str,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Integer Overflow in {} of Day {}!",
+            self.operation, self.day
+        )
+    }
+}
+
+impl core::error::Error for Overflow {}
+
+/// Arithmetic of the solution of a day. With the `checked` feature every operation is
+/// checked and overflows are returned as [`Overflow`] errors, without it the plain
+/// operators are used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arithmetic {
+    pub day: u8,
+}
+
+impl Arithmetic {
+    #[must_use]
+    pub const fn new(day: u8) -> Self {
+        Self { day }
+    }
+
+    fn overflow(self, operation: &'static str) -> Overflow {
+        Overflow {
+            day: self.day,
+            operation,
+        }
+    }
+
+    /// `a + b`
+    ///
+    /// # Errors
+    ///
+    /// On overflow with the `checked` feature.
+    pub fn add<T: CheckedAdd>(self, operation: &'static str, a: T, b: T) -> Result<T, Overflow> {
+        if cfg!(feature = "checked") {
+            a.checked_add(&b).ok_or_else(|| self.overflow(operation))
+        } else {
+            Ok(a + b)
+        }
+    }
+
+    /// `a - b`
+    ///
+    /// # Errors
+    ///
+    /// On overflow with the `checked` feature.
+    pub fn sub<T: CheckedSub>(self, operation: &'static str, a: T, b: T) -> Result<T, Overflow> {
+        if cfg!(feature = "checked") {
+            a.checked_sub(&b).ok_or_else(|| self.overflow(operation))
+        } else {
+            Ok(a - b)
+        }
+    }
+
+    /// `a * b`
+    ///
+    /// # Errors
+    ///
+    /// On overflow with the `checked` feature.
+    pub fn mul<T: CheckedMul>(self, operation: &'static str, a: T, b: T) -> Result<T, Overflow> {
+        if cfg!(feature = "checked") {
+            a.checked_mul(&b).ok_or_else(|| self.overflow(operation))
+        } else {
+            Ok(a * b)
+        }
+    }
+
+    /// `base` to the power of `exponent`
+    ///
+    /// # Errors
+    ///
+    /// On overflow with the `checked` feature.
+    pub fn pow<T: CheckedMul + One + Clone>(
+        self,
+        operation: &'static str,
+        base: T,
+        exponent: usize,
+    ) -> Result<T, Overflow> {
+        if cfg!(feature = "checked") {
+            checked_pow(base, exponent).ok_or_else(|| self.overflow(operation))
+        } else {
+            Ok(pow(base, exponent))
+        }
+    }
+
+    /// Sum of the values.
+    ///
+    /// # Errors
+    ///
+    /// On overflow with the `checked` feature.
+    pub fn sum<T: CheckedAdd + Zero>(
+        self,
+        operation: &'static str,
+        values: impl IntoIterator<Item = T>,
+    ) -> Result<T, Overflow> {
+        values
+            .into_iter()
+            .try_fold(T::zero(), |sum, value| self.add(operation, sum, value))
+    }
+
+    /// Product of the values.
+    ///
+    /// # Errors
+    ///
+    /// On overflow with the `checked` feature.
+    pub fn product<T: CheckedMul + One>(
+        self,
+        operation: &'static str,
+        values: impl IntoIterator<Item = T>,
+    ) -> Result<T, Overflow> {
+        values.into_iter().try_fold(T::one(), |product, value| {
+            self.mul(operation, product, value)
+        })
+    }
+
+    /// Converts the value to another integer type, always checked.
+    ///
+    /// # Errors
+    ///
+    /// If the value doesn't fit into the type.
+    pub fn convert<T, U: TryFrom<T>>(
+        self,
+        operation: &'static str,
+        value: T,
+    ) -> Result<U, Overflow> {
+        U::try_from(value).map_err(|_| self.overflow(operation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Arithmetic, Overflow};
+
+    const ARITHMETIC: Arithmetic = Arithmetic::new(7);
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(ARITHMETIC.add("a + b", 2u8, 3), Ok(5));
+        assert_eq!(ARITHMETIC.sub("a - b", 3u8, 2), Ok(1));
+        assert_eq!(ARITHMETIC.mul("a * b", 4u8, 5), Ok(20));
+        assert_eq!(ARITHMETIC.pow("a^b", 2u64, 10), Ok(1024));
+        assert_eq!(ARITHMETIC.sum("sum", [1u32, 2, 3]), Ok(6));
+        assert_eq!(ARITHMETIC.product("product", [2u32, 3, 4]), Ok(24));
+        assert_eq!(ARITHMETIC.convert::<usize, u8>("rank", 255), Ok(255));
+        assert_eq!(
+            ARITHMETIC.convert::<usize, u8>("rank", 256),
+            Err(Overflow {
+                day: 7,
+                operation: "rank"
+            })
+        );
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn test_overflow() {
+        let overflow = ARITHMETIC.mul("bid * rank", 16u8, 16).unwrap_err();
+        assert_eq!(
+            overflow.to_string(),
+            "Integer Overflow in bid * rank of Day 7!"
+        );
+        assert!(ARITHMETIC.add("a + b", u64::MAX, 1).is_err());
+        assert!(ARITHMETIC.sub("a - b", 0u32, 1).is_err());
+        assert!(ARITHMETIC.pow("a^b", 2u64, 64).is_err());
+        assert!(ARITHMETIC.sum("sum", [u8::MAX, 1]).is_err());
+        assert!(ARITHMETIC.product("product", [u8::MAX, 2]).is_err());
+    }
+}
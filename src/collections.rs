@@ -0,0 +1,7 @@
+//! Hash maps and sets of the solutions, from hashbrown with the `hashbrown` feature so they
+//! are available without std, otherwise from std.
+
+#[cfg(feature = "hashbrown")]
+pub use hashbrown::{hash_map, hash_set, HashMap, HashSet};
+#[cfg(not(feature = "hashbrown"))]
+pub use std::collections::{hash_map, hash_set, HashMap, HashSet};
@@ -0,0 +1,95 @@
+use argon2::Argon2;
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+/// Environment variable holding the local passphrase inputs are encrypted with.
+pub const PASSPHRASE_ENV: &str = "AOC_PASSPHRASE";
+
+/// Extension appended to the file name of encrypted inputs.
+pub const EXTENSION: &str = "enc";
+
+/// Marks and versions the format: magic, key salt, nonce, then ciphertext with tag.
+const MAGIC: &[u8] = b"AOCENC1\n";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// The passphrase from the environment.
+///
+/// # Errors
+///
+/// If the passphrase isn't set or empty.
+pub fn passphrase() -> anyhow::Result<String> {
+    std::env::var(PASSPHRASE_ENV)
+        .ok()
+        .filter(|passphrase| !passphrase.is_empty())
+        .ok_or(anyhow::anyhow!(
+            "Missing Passphrase! Set {PASSPHRASE_ENV} to encrypt or decrypt inputs."
+        ))
+}
+
+fn cipher(passphrase: &str, salt: &[u8]) -> anyhow::Result<ChaCha20Poly1305> {
+    let mut key = [0; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|err| anyhow::anyhow!("Key Derivation Error: {err}"))?;
+    Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+}
+
+/// Encrypts the data with a key derived from the passphrase and a random salt.
+///
+/// # Errors
+///
+/// If the random number generator or the encryption fails.
+pub fn encrypt(plaintext: &[u8], passphrase: &str) -> anyhow::Result<Vec<u8>> {
+    let mut salt = [0; SALT_LEN];
+    let mut nonce = [0; NONCE_LEN];
+    getrandom::getrandom(&mut salt)?;
+    getrandom::getrandom(&mut nonce)?;
+
+    let ciphertext = cipher(passphrase, &salt)?
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .map_err(|_| anyhow::anyhow!("Encryption Error!"))?;
+    Ok([MAGIC, &salt, &nonce, &ciphertext].concat())
+}
+
+/// Decrypts data written by [`encrypt`].
+///
+/// # Errors
+///
+/// If the data isn't encrypted input, was tampered with or the passphrase is wrong.
+pub fn decrypt(data: &[u8], passphrase: &str) -> anyhow::Result<Vec<u8>> {
+    let data = data
+        .strip_prefix(MAGIC)
+        .filter(|data| data.len() >= SALT_LEN + NONCE_LEN)
+        .ok_or(anyhow::anyhow!("Not an Encrypted Input!"))?;
+    let (salt, data) = data.split_at(SALT_LEN);
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    cipher(passphrase, salt)?
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow::anyhow!("Decryption Error! Wrong passphrase?"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decrypt, encrypt};
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let input = b"1abc2\npqr3stu8vwx\n";
+        let encrypted = encrypt(input, "secret").unwrap();
+        assert!(!encrypted.windows(input.len()).any(|window| window == input));
+        assert_eq!(decrypt(&encrypted, "secret").unwrap(), input);
+
+        // salt and nonce are random, the same input encrypts differently
+        assert_ne!(encrypt(input, "secret").unwrap(), encrypted);
+
+        assert!(decrypt(&encrypted, "wrong").is_err());
+        let mut tampered = encrypted.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&tampered, "secret").is_err());
+        assert!(decrypt(input, "secret").is_err());
+    }
+}
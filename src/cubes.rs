@@ -0,0 +1,412 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Write};
+
+use crate::collections::HashMap;
+
+/// Interned id of a cube color, see [`Colors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ColorId(usize);
+
+impl ColorId {
+    /// Index of the color, the number of colors interned before it.
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Names of the cube colors of the games, interned to ids in order of their first
+/// appearance.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Colors {
+    names: Vec<String>,
+    ids: HashMap<String, ColorId>,
+}
+
+impl Colors {
+    /// Id of the color, interning it if it is new.
+    pub fn intern(&mut self, name: &str) -> ColorId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = ColorId(self.names.len());
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        id
+    }
+
+    /// Id of the color, `None` if it was never interned.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<ColorId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Name of the color.
+    ///
+    /// # Panics
+    ///
+    /// If the id is from other colors.
+    #[must_use]
+    pub fn name(&self, id: ColorId) -> &str {
+        &self.names[id.0]
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Ids and names of the colors, in order of their ids.
+    pub fn iter(&self) -> impl Iterator<Item = (ColorId, &str)> {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (ColorId(index), name.as_str()))
+    }
+}
+
+/// Cubes of one draw, the count of each color in the order they were drawn. A draw has at
+/// least one cube and each color once, so it always displays as it is parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Draw(Vec<(ColorId, usize)>);
+
+impl Draw {
+    /// The draw of the cubes.
+    ///
+    /// # Errors
+    ///
+    /// If there are no cubes or a color is drawn twice.
+    pub fn new(cubes: Vec<(ColorId, usize)>) -> anyhow::Result<Self> {
+        if cubes.is_empty() {
+            return Err(anyhow::anyhow!("Empty Draw!"));
+        }
+        if cubes
+            .iter()
+            .enumerate()
+            .any(|(index, (color, _))| cubes[..index].iter().any(|(id, _)| id == color))
+        {
+            return Err(anyhow::anyhow!("Duplicate color!"));
+        }
+        Ok(Self(cubes))
+    }
+
+    /// Colors and counts of the cubes, in the order they were drawn.
+    #[must_use]
+    pub fn cubes(&self) -> &[(ColorId, usize)] {
+        &self.0
+    }
+
+    /// Number of cubes of the color, 0 if there are none.
+    #[must_use]
+    pub fn count(&self, color: ColorId) -> usize {
+        self.0
+            .iter()
+            .find(|(id, _)| *id == color)
+            .map_or(0, |(_, count)| *count)
+    }
+}
+
+impl Draw {
+    /// The draw in the format it is parsed from, like `3 blue, 4 red`.
+    #[must_use]
+    pub fn display<'a>(&'a self, colors: &'a Colors) -> DisplayDraw<'a> {
+        DisplayDraw { draw: self, colors }
+    }
+}
+
+/// Formats a [`Draw`] with the names of its colors, see [`Draw::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayDraw<'a> {
+    draw: &'a Draw,
+    colors: &'a Colors,
+}
+
+impl fmt::Display for DisplayDraw<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, &(color, count)) in self.draw.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{count} {}", self.colors.name(color))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Game {
+    pub id: usize,
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    /// The game in the format it is parsed from, like `Game 1: 3 blue, 4 red; 1 red`,
+    /// so parsing it with the same colors gives the game back.
+    #[must_use]
+    pub fn display<'a>(&'a self, colors: &'a Colors) -> DisplayGame<'a> {
+        DisplayGame { game: self, colors }
+    }
+}
+
+/// Formats a [`Game`] with the names of its colors, see [`Game::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayGame<'a> {
+    game: &'a Game,
+    colors: &'a Colors,
+}
+
+impl fmt::Display for DisplayGame<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}:", self.game.id)?;
+        for (index, draw) in self.game.draws.iter().enumerate() {
+            let separator = if index > 0 { "; " } else { " " };
+            write!(f, "{separator}{}", draw.display(self.colors))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a draw like `3 blue, 4 red`, interning its colors. Whitespace around the cubes
+/// and empty cubes are skipped, spaces in color names collapsed.
+///
+/// # Errors
+///
+/// If the draw has no cubes, a cube isn't a count and a color, or a color is drawn twice.
+pub fn parse_draw(draw: &str, colors: &mut Colors) -> anyhow::Result<Draw> {
+    let mut cubes = Vec::new();
+    for cube in draw
+        .split(',')
+        .map(str::trim)
+        .filter(|cube| !cube.is_empty())
+    {
+        let (count, color) = cube
+            .split_once(char::is_whitespace)
+            .ok_or(anyhow::anyhow!("Invalid Draw!"))?;
+        let count =
+            str::parse::<usize>(count).map_err(|_| anyhow::anyhow!("Invalid Draw Count!"))?;
+        let color = color.split_whitespace().collect::<Vec<_>>().join(" ");
+        if color.is_empty() {
+            return Err(anyhow::anyhow!("Invalid color!"));
+        }
+        let color = colors.intern(&color);
+        if cubes.iter().any(|(id, _)| *id == color) {
+            return Err(anyhow::anyhow!("Duplicate color {}!", colors.name(color)));
+        }
+        cubes.push((color, count));
+    }
+
+    Draw::new(cubes)
+}
+
+/// Parses a game like `Game 1: 3 blue, 4 red; 1 red, 2 green`, interning its colors.
+/// Whitespace around the parts is skipped, a game without draws is `Game 1:`.
+///
+/// # Errors
+///
+/// If the line isn't a game, see [`parse_draw`] for the draws.
+pub fn parse_game(line: &str, colors: &mut Colors) -> anyhow::Result<Game> {
+    let (game, rest) = line
+        .split_once(':')
+        .ok_or(anyhow::anyhow!("Invalid game!"))?;
+    let id = game
+        .trim()
+        .strip_prefix("Game")
+        .and_then(|id| str::parse::<usize>(id.trim_start()).ok())
+        .ok_or(anyhow::anyhow!("Invalid Game Id!"))?;
+    let draws = if rest.trim().is_empty() {
+        Vec::new()
+    } else {
+        rest.split(';')
+            .map(|draw| parse_draw(draw, colors))
+            .collect::<anyhow::Result<_>>()?
+    };
+
+    Ok(Game { id, draws })
+}
+
+/// Parses a game per line, with the colors interned over all of them. Blank lines are
+/// skipped.
+///
+/// # Errors
+///
+/// If a line isn't a game, see [`parse_game`].
+pub fn parse_games_from_contents(contents: &str) -> anyhow::Result<(Colors, Vec<Game>)> {
+    let mut colors = Colors::default();
+    let games = contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_game(line, &mut colors))
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok((colors, games))
+}
+
+/// The games in their canonical format, a line per game.
+///
+/// # Errors
+///
+/// If a line isn't a game, see [`parse_game`].
+pub fn normalize(contents: &str) -> anyhow::Result<String> {
+    let (colors, games) = parse_games_from_contents(contents)?;
+    let mut normalized = String::new();
+    for game in &games {
+        let _ = writeln!(normalized, "{}", game.display(&colors));
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+
+    use proptest::{collection::vec, prelude::*, sample::subsequence};
+
+    use super::{normalize, parse_draw, parse_game, parse_games_from_contents, Colors, Draw, Game};
+
+    #[test]
+    fn test_colors() {
+        let mut colors = Colors::default();
+        let red = colors.intern("red");
+        let blue = colors.intern("blue");
+        assert_eq!(colors.intern("red"), red);
+        assert_eq!(colors.get("blue"), Some(blue));
+        assert_eq!(colors.get("green"), None);
+        assert_eq!(colors.name(blue), "blue");
+        assert_eq!(
+            colors
+                .iter()
+                .map(|(id, name)| (id.index(), name))
+                .collect::<Vec<_>>(),
+            [(0, "red"), (1, "blue")]
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 1 violet, 2 light blue\n";
+        let (colors, games) = parse_games_from_contents(input).unwrap();
+        assert_eq!(colors.len(), 5);
+        let [blue, red, green, violet, light_blue] =
+            ["blue", "red", "green", "violet", "light blue"].map(|name| colors.get(name).unwrap());
+        assert_eq!(games[0].id, 1);
+        assert_eq!(
+            games[0].draws[0],
+            Draw::new(vec![(blue, 3), (red, 4)]).unwrap()
+        );
+        assert_eq!(games[0].draws[1].count(blue), 6);
+        assert_eq!(games[0].draws[2].count(red), 0);
+        assert_eq!(games[0].draws[2].count(green), 2);
+        assert_eq!(
+            games[1].draws,
+            [Draw::new(vec![(violet, 1), (light_blue, 2)]).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut colors = Colors::default();
+        assert!(parse_draw("3", &mut colors).is_err());
+        assert!(parse_draw("x red", &mut colors).is_err());
+        assert!(parse_draw("3 red, 4 red", &mut colors).is_err());
+        assert!(parse_draw(" , ", &mut colors).is_err());
+        let red = colors.intern("red");
+        assert!(Draw::new(Vec::new()).is_err());
+        assert!(Draw::new(vec![(red, 3), (red, 4)]).is_err());
+        assert!(parse_game("Game 1: 3 red;", &mut colors).is_err());
+        assert!(parse_game("Game 1: 3 red; ;1 red", &mut colors).is_err());
+        assert!(parse_game("Round 1: 3 red", &mut colors).is_err());
+        assert!(parse_game("Game 1 3 red", &mut colors).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let input =
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\nGame 2: 1 light blue\n";
+        let (colors, games) = parse_games_from_contents(input).unwrap();
+        assert_eq!(
+            games[0].display(&colors).to_string(),
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+        );
+        assert_eq!(
+            games[1].draws[0].display(&colors).to_string(),
+            "1 light blue"
+        );
+        assert_eq!(normalize(input).unwrap(), input);
+
+        let messy = "  Game 1 :3 blue,4 red ;1 red ,2   green,  6 blue;2 green\r\n\nGame  2:  1 light   blue,\n";
+        assert_eq!(normalize(messy).unwrap(), input);
+        let game = Game {
+            id: 3,
+            draws: Vec::new(),
+        };
+        assert_eq!(game.display(&colors).to_string(), "Game 3:");
+        assert_eq!(parse_game(" Game 3: ", &mut colors.clone()).unwrap(), game);
+    }
+
+    /// Id and draws of a game, the draws as indices of colors and counts.
+    type GameCubes = (usize, Vec<Vec<(usize, usize)>>);
+
+    /// Names of the colors and games with draws of them.
+    fn games_strategy() -> impl Strategy<Value = (Vec<String>, Vec<GameCubes>)> {
+        vec("[a-z]{1,6}( [a-z]{1,6})?", 1..6).prop_flat_map(|mut names| {
+            names.sort();
+            names.dedup();
+            let indices = (0..names.len()).collect::<Vec<_>>();
+            let len = names.len();
+            let cubes = subsequence(indices, 1..=len)
+                .prop_flat_map(|colors| {
+                    let len = colors.len();
+                    (Just(colors), vec(0..30usize, len))
+                })
+                .prop_map(|(colors, counts)| colors.into_iter().zip(counts).collect::<Vec<_>>())
+                .prop_shuffle();
+            (Just(names), vec((0..1000usize, vec(cubes, 0..5)), 0..8))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_round_trip((names, games) in games_strategy(), messy in any::<bool>()) {
+            let mut colors = Colors::default();
+            let ids = names.iter().map(|name| colors.intern(name)).collect::<Vec<_>>();
+            let games = games
+                .into_iter()
+                .map(|(id, draws)| Game {
+                    id,
+                    draws: draws
+                        .into_iter()
+                        .map(|cubes| {
+                            Draw::new(cubes.into_iter().map(|(color, count)| (ids[color], count)).collect())
+                                .unwrap()
+                        })
+                        .collect(),
+                })
+                .collect::<Vec<_>>();
+
+            let mut contents = String::new();
+            for game in &games {
+                let mut line = game.display(&colors).to_string();
+                if messy {
+                    let spaced = line.replace(", ", " ,").replace("; ", " ;  ").replace(':', " : ");
+                    line = format!(" {spaced} \r");
+                }
+                let mut parsed_colors = colors.clone();
+                let parsed = parse_game(&line, &mut parsed_colors);
+                prop_assert_eq!(&parsed.unwrap(), game);
+                prop_assert_eq!(&parsed_colors, &colors);
+                let _ = writeln!(contents, "{}", game.display(&colors));
+            }
+            let normalized = normalize(&contents).unwrap();
+            prop_assert_eq!(&normalized, &contents);
+            prop_assert_eq!(normalize(&normalized).unwrap(), normalized);
+        }
+    }
+}
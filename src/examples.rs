@@ -0,0 +1,156 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// An example puzzle input with the expected answers from its header.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub path: PathBuf,
+    pub year: u16,
+    pub day: u8,
+    /// Expected answer by part number
+    pub answers: BTreeMap<u8, String>,
+    pub input: String,
+}
+
+/// Directory the example files of this crate are kept in, one directory per year and day.
+#[must_use]
+pub fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")
+}
+
+/// Splits the header lines, the leading lines starting with `# ` (without the prefix),
+/// from the puzzle input of an example file.
+#[must_use]
+pub fn split_header(contents: &str) -> (Vec<&str>, &str) {
+    let mut header = Vec::new();
+    let mut input = contents;
+
+    while let Some(line) = input.lines().next() {
+        let Some(comment) = line.strip_prefix("# ").or((line == "#").then_some("")) else {
+            break;
+        };
+        header.push(comment);
+        input = input[line.len()..]
+            .strip_prefix('\n')
+            .unwrap_or(&input[line.len()..]);
+    }
+
+    (header, input)
+}
+
+/// Splits an example file into the expected answers and the puzzle input.
+///
+/// Header lines like `# part1: 142` declare the expected answer of a part, all other
+/// header lines are comments.
+///
+/// # Errors
+///
+/// If a part number in the header is invalid or declared twice.
+pub fn parse_example(contents: &str) -> anyhow::Result<(BTreeMap<u8, String>, String)> {
+    let (header, input) = split_header(contents);
+    let mut answers = BTreeMap::new();
+
+    for line in header {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let Some(part) = key.trim().strip_prefix("part") else {
+            continue;
+        };
+        let part = str::parse::<u8>(part).map_err(|_| anyhow::anyhow!("Invalid Part: {key}!"))?;
+        if answers.insert(part, value.trim().to_string()).is_some() {
+            return Err(anyhow::anyhow!("Duplicate Answer for Part {part}!"));
+        }
+    }
+
+    Ok((answers, input.to_string()))
+}
+
+/// Subdirectories named `<prefix><number>`, like `2023` or `day5`.
+fn numbered_dirs<T: std::str::FromStr>(
+    dir: &Path,
+    prefix: &str,
+) -> anyhow::Result<Vec<(T, PathBuf)>> {
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(number) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(prefix))
+            .and_then(|number| str::parse::<T>(number).ok())
+        else {
+            continue;
+        };
+        if path.is_dir() {
+            dirs.push((number, path));
+        }
+    }
+    Ok(dirs)
+}
+
+/// Loads all example files `<year>/day<N>/*.txt` in the directory, sorted by year, day and
+/// file name.
+///
+/// # Errors
+///
+/// On I/O errors or invalid example headers.
+pub fn load_examples(dir: &Path) -> anyhow::Result<Vec<Example>> {
+    let mut examples = Vec::new();
+
+    for (year, year_dir) in numbered_dirs::<u16>(dir, "")? {
+        for (day, day_dir) in numbered_dirs::<u8>(&year_dir, "day")? {
+            for entry in fs::read_dir(&day_dir)? {
+                let path = entry?.path();
+                if path.extension().is_none_or(|extension| extension != "txt") {
+                    continue;
+                }
+                let (answers, input) = parse_example(&fs::read_to_string(&path)?)
+                    .map_err(|err| anyhow::anyhow!("{}: {err}", path.display()))?;
+                examples.push(Example {
+                    path,
+                    year,
+                    day,
+                    answers,
+                    input,
+                });
+            }
+        }
+    }
+
+    examples.sort_by(|a, b| (a.year, a.day, &a.path).cmp(&(b.year, b.day, &b.path)));
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::parse_example;
+
+    #[test]
+    fn test_parse_example() {
+        let (answers, input) =
+            parse_example("# part1: 142\n# from the puzzle text\n# part2: 281\n1abc2\n").unwrap();
+        assert_eq!(
+            answers,
+            BTreeMap::from([(1, "142".to_string()), (2, "281".to_string())])
+        );
+        assert_eq!(input, "1abc2\n");
+
+        // galaxies at the start of a line are not a header
+        let (answers, input) = parse_example("# part1: 374\n#....\n..#..\n").unwrap();
+        assert_eq!(answers, BTreeMap::from([(1, "374".to_string())]));
+        assert_eq!(input, "#....\n..#..\n");
+
+        let (answers, input) = parse_example("no header").unwrap();
+        assert!(answers.is_empty());
+        assert_eq!(input, "no header");
+
+        assert!(parse_example("# partx: 1\n").is_err());
+        assert!(parse_example("# part1: 1\n# part1: 2\n").is_err());
+    }
+}
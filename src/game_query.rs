@@ -0,0 +1,480 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::cubes::{ColorId, Colors, Game};
+
+/// Aggregate of the counts of a color over the draws of a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Function {
+    Max,
+    Min,
+    Sum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Expression of the query language, either a number or a condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Number(i64),
+    /// Id of the game
+    Id,
+    /// Number of draws of the game
+    Draws,
+    Aggregate(Function, ColorId),
+    Arithmetic(Operator, Box<Expr>, Box<Expr>),
+    Compare(Comparison, Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn is_condition(&self) -> bool {
+        matches!(
+            self,
+            Self::Compare(..) | Self::Not(_) | Self::And(..) | Self::Or(..)
+        )
+    }
+
+    /// Value of the number expression for the game.
+    fn number(&self, game: &Game) -> anyhow::Result<i64> {
+        let overflow = || anyhow::anyhow!("Integer Overflow in Query!");
+        Ok(match self {
+            Self::Number(n) => *n,
+            Self::Id => i64::try_from(game.id).map_err(|_| overflow())?,
+            Self::Draws => i64::try_from(game.draws.len()).map_err(|_| overflow())?,
+            Self::Aggregate(function, color) => {
+                let mut counts = game.draws.iter().map(|draw| draw.count(*color));
+                let value = match function {
+                    Function::Max => counts.max().unwrap_or(0),
+                    Function::Min => counts.min().unwrap_or(0),
+                    Function::Sum => counts
+                        .try_fold(0usize, usize::checked_add)
+                        .ok_or_else(overflow)?,
+                };
+                i64::try_from(value).map_err(|_| overflow())?
+            }
+            Self::Arithmetic(operator, a, b) => {
+                let (a, b) = (a.number(game)?, b.number(game)?);
+                match operator {
+                    Operator::Add => a.checked_add(b),
+                    Operator::Sub => a.checked_sub(b),
+                    Operator::Mul => a.checked_mul(b),
+                }
+                .ok_or_else(overflow)?
+            }
+            Self::Compare(..) | Self::Not(_) | Self::And(..) | Self::Or(..) => {
+                return Err(anyhow::anyhow!("Condition in Number!"))
+            }
+        })
+    }
+
+    /// Value of the condition for the game.
+    fn condition(&self, game: &Game) -> anyhow::Result<bool> {
+        Ok(match self {
+            Self::Compare(comparison, a, b) => {
+                let (a, b) = (a.number(game)?, b.number(game)?);
+                match comparison {
+                    Comparison::Eq => a == b,
+                    Comparison::Ne => a != b,
+                    Comparison::Lt => a < b,
+                    Comparison::Le => a <= b,
+                    Comparison::Gt => a > b,
+                    Comparison::Ge => a >= b,
+                }
+            }
+            Self::Not(a) => !a.condition(game)?,
+            Self::And(a, b) => a.condition(game)? && b.condition(game)?,
+            Self::Or(a, b) => a.condition(game)? || b.condition(game)?,
+            _ => return Err(anyhow::anyhow!("Number in Condition!")),
+        })
+    }
+}
+
+/// Condition on games, like `red <= 12 and (blue >= 3 or draws > 4)`.
+///
+/// Numbers are integers, `id`, `draws` and the aggregates `max(color)`, `min(color)` and
+/// `sum(color)` over the draws, combined with `+`, `-` and `*`. A bare color is its
+/// maximum, so `red <= 12` holds if every draw had at most 12 red cubes. Conditions
+/// compare numbers with `==`, `!=`, `<`, `<=`, `>` and `>=` and are combined with `not`,
+/// `and` and `or`. Colors with spaces are quoted, `max("light blue")`, and must appear in
+/// the games.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query(pub Expr);
+
+impl Query {
+    /// Parses the query over the colors of the games.
+    ///
+    /// # Errors
+    ///
+    /// If the query is invalid, has an unknown color or is not a condition.
+    pub fn parse(query: &str, colors: &Colors) -> anyhow::Result<Self> {
+        let expr = Parser::parse(query, colors)?;
+        if !expr.is_condition() {
+            return Err(anyhow::anyhow!("Invalid Query, Expected a Condition!"));
+        }
+        Ok(Self(expr))
+    }
+
+    /// Whether the game meets the condition.
+    ///
+    /// # Errors
+    ///
+    /// On integer overflow.
+    pub fn matches(&self, game: &Game) -> anyhow::Result<bool> {
+        self.0.condition(game)
+    }
+}
+
+/// Number computed from a game in the language of [`Query`], like `max(red) * max(blue)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metric(pub Expr);
+
+impl Metric {
+    /// Parses the metric over the colors of the games.
+    ///
+    /// # Errors
+    ///
+    /// If the metric is invalid, has an unknown color or is not a number.
+    pub fn parse(metric: &str, colors: &Colors) -> anyhow::Result<Self> {
+        let expr = Parser::parse(metric, colors)?;
+        if expr.is_condition() {
+            return Err(anyhow::anyhow!("Invalid Query, Expected a Number!"));
+        }
+        Ok(Self(expr))
+    }
+
+    /// Value of the metric for the game.
+    ///
+    /// # Errors
+    ///
+    /// On integer overflow.
+    pub fn eval(&self, game: &Game) -> anyhow::Result<i64> {
+        self.0.number(game)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Number(i64),
+    Word(String),
+    /// Color name in quotes
+    Quoted(String),
+    Symbol(&'static str),
+}
+
+const SYMBOLS: [&str; 11] = ["==", "!=", "<=", ">=", "<", ">", "(", ")", "+", "-", "*"];
+
+fn tokenize(query: &str) -> anyhow::Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut rest = query.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let len = if c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let number = rest[..len].parse().map_err(|_| {
+                anyhow::anyhow!("Invalid Query, Number {} too large!", &rest[..len])
+            })?;
+            tokens.push(Token::Number(number));
+            len
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Word(rest[..len].into()));
+            len
+        } else if c == '"' {
+            let end = rest[1..]
+                .find('"')
+                .ok_or_else(|| anyhow::anyhow!("Invalid Query, Unterminated Quote!"))?;
+            tokens.push(Token::Quoted(rest[1..=end].into()));
+            end + 2
+        } else {
+            let symbol = SYMBOLS
+                .into_iter()
+                .find(|symbol| rest.starts_with(symbol))
+                .ok_or_else(|| anyhow::anyhow!("Invalid Query, Unexpected {c}!"))?;
+            tokens.push(Token::Symbol(symbol));
+            symbol.len()
+        };
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser, from `or` binding loosest to the atoms.
+struct Parser<'c> {
+    tokens: Vec<Token>,
+    position: usize,
+    colors: &'c Colors,
+}
+
+impl Parser<'_> {
+    fn parse(query: &str, colors: &Colors) -> anyhow::Result<Expr> {
+        let mut parser = Parser {
+            tokens: tokenize(query)?,
+            position: 0,
+            colors,
+        };
+        let expr = parser.or()?;
+        if let Some(token) = parser.peek() {
+            return Err(anyhow::anyhow!("Invalid Query, Unexpected {token:?}!"));
+        }
+        Ok(expr)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> anyhow::Result<Token> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Invalid Query, Unexpected End!"))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    /// Consumes the token if it is next.
+    fn eat(&mut self, token: &Token) -> bool {
+        let next = self.peek() == Some(token);
+        if next {
+            self.position += 1;
+        }
+        next
+    }
+
+    fn expect(&mut self, symbol: &'static str) -> anyhow::Result<()> {
+        if self.eat(&Token::Symbol(symbol)) {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Invalid Query, Expected {symbol}!"))
+        }
+    }
+
+    fn condition(expr: Expr) -> anyhow::Result<Box<Expr>> {
+        if expr.is_condition() {
+            Ok(Box::new(expr))
+        } else {
+            Err(anyhow::anyhow!("Invalid Query, Expected a Condition!"))
+        }
+    }
+
+    fn number(expr: Expr) -> anyhow::Result<Box<Expr>> {
+        if expr.is_condition() {
+            Err(anyhow::anyhow!("Invalid Query, Expected a Number!"))
+        } else {
+            Ok(Box::new(expr))
+        }
+    }
+
+    fn or(&mut self) -> anyhow::Result<Expr> {
+        let mut expr = self.and()?;
+        while self.eat(&Token::Word("or".into())) {
+            expr = Expr::Or(Self::condition(expr)?, Self::condition(self.and()?)?);
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> anyhow::Result<Expr> {
+        let mut expr = self.not()?;
+        while self.eat(&Token::Word("and".into())) {
+            expr = Expr::And(Self::condition(expr)?, Self::condition(self.not()?)?);
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> anyhow::Result<Expr> {
+        if self.eat(&Token::Word("not".into())) {
+            return Ok(Expr::Not(Self::condition(self.not()?)?));
+        }
+        self.compare()
+    }
+
+    fn compare(&mut self) -> anyhow::Result<Expr> {
+        let expr = self.sum()?;
+        let comparison = match self.peek() {
+            Some(Token::Symbol("==")) => Comparison::Eq,
+            Some(Token::Symbol("!=")) => Comparison::Ne,
+            Some(Token::Symbol("<")) => Comparison::Lt,
+            Some(Token::Symbol("<=")) => Comparison::Le,
+            Some(Token::Symbol(">")) => Comparison::Gt,
+            Some(Token::Symbol(">=")) => Comparison::Ge,
+            _ => return Ok(expr),
+        };
+        self.position += 1;
+        Ok(Expr::Compare(
+            comparison,
+            Self::number(expr)?,
+            Self::number(self.sum()?)?,
+        ))
+    }
+
+    fn sum(&mut self) -> anyhow::Result<Expr> {
+        let mut expr = self.product()?;
+        loop {
+            let operator = match self.peek() {
+                Some(Token::Symbol("+")) => Operator::Add,
+                Some(Token::Symbol("-")) => Operator::Sub,
+                _ => return Ok(expr),
+            };
+            self.position += 1;
+            expr = Expr::Arithmetic(
+                operator,
+                Self::number(expr)?,
+                Self::number(self.product()?)?,
+            );
+        }
+    }
+
+    fn product(&mut self) -> anyhow::Result<Expr> {
+        let mut expr = self.atom()?;
+        while self.eat(&Token::Symbol("*")) {
+            expr = Expr::Arithmetic(
+                Operator::Mul,
+                Self::number(expr)?,
+                Self::number(self.atom()?)?,
+            );
+        }
+        Ok(expr)
+    }
+
+    /// Id of a color of the games, so a typo doesn't silently match every game.
+    fn known(&self, name: &str) -> anyhow::Result<ColorId> {
+        self.colors
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("Invalid Query, Unknown color {name}!"))
+    }
+
+    fn color(&mut self) -> anyhow::Result<ColorId> {
+        match self.next()? {
+            Token::Word(name) | Token::Quoted(name) => self.known(&name),
+            token => Err(anyhow::anyhow!(
+                "Invalid Query, Expected a Color, not {token:?}!"
+            )),
+        }
+    }
+
+    fn atom(&mut self) -> anyhow::Result<Expr> {
+        let word = match self.next()? {
+            Token::Number(n) => return Ok(Expr::Number(n)),
+            Token::Quoted(name) => return Ok(Expr::Aggregate(Function::Max, self.known(&name)?)),
+            Token::Symbol("(") => {
+                let expr = self.or()?;
+                self.expect(")")?;
+                return Ok(expr);
+            }
+            Token::Symbol(symbol) => {
+                return Err(anyhow::anyhow!("Invalid Query, Unexpected {symbol}!"))
+            }
+            Token::Word(word) => word,
+        };
+        let function = match word.as_str() {
+            "id" => return Ok(Expr::Id),
+            "draws" => return Ok(Expr::Draws),
+            "and" | "or" | "not" => {
+                return Err(anyhow::anyhow!("Invalid Query, Unexpected {word}!"))
+            }
+            "max" => Function::Max,
+            "min" => Function::Min,
+            "sum" => Function::Sum,
+            color => return Ok(Expr::Aggregate(Function::Max, self.known(color)?)),
+        };
+        self.expect("(")?;
+        let color = self.color()?;
+        self.expect(")")?;
+        Ok(Expr::Aggregate(function, color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Metric, Query};
+    use crate::cubes::parse_games_from_contents;
+
+    const GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    fn matching(query: &str) -> Vec<usize> {
+        let (colors, games) = parse_games_from_contents(GAMES).unwrap();
+        let query = Query::parse(query, &colors).unwrap();
+        games
+            .iter()
+            .filter(|game| query.matches(game).unwrap())
+            .map(|game| game.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_query() {
+        assert_eq!(
+            matching("red <= 12 and green <= 13 and blue <= 14"),
+            [1, 2, 5]
+        );
+        assert_eq!(matching("max(green) == 2"), [1]);
+        assert_eq!(matching("sum(red) > 20"), [3, 4]);
+        assert_eq!(matching("red <= 12 and (blue >= 3 or draws > 4)"), [1, 2]);
+        assert_eq!(matching("not min(red) > 0 or id * 2 == 10"), [1, 2, 5]);
+        assert_eq!(matching("id - 1 + 2 * 2 == 7"), [4]);
+        assert_eq!(matching("\"red\" > 6"), [3, 4]);
+    }
+
+    #[test]
+    fn test_metric() {
+        let (colors, games) = parse_games_from_contents(GAMES).unwrap();
+        let power = Metric::parse("max(red) * max(green) * max(blue)", &colors).unwrap();
+        let powers = games
+            .iter()
+            .map(|game| power.eval(game).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(powers, [48, 12, 1560, 630, 36]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let (colors, _) = parse_games_from_contents(GAMES).unwrap();
+        for query in [
+            "red",
+            "red <=",
+            "red <= 12 and 3",
+            "(red <= 12",
+            "max(3) > 1",
+            "red < 1 < 2",
+            "red + (blue > 2) > 1",
+            "red $ 2",
+            "99999999999999999999 > 1",
+            "\"red",
+            "gren <= 3",
+            "max(violet) == 0",
+            "\"light blue\" < 1",
+        ] {
+            assert!(Query::parse(query, &colors).is_err(), "{query}");
+        }
+        assert!(Metric::parse("red > 1", &colors).is_err());
+        assert!(Metric::parse("sum(gren)", &colors).is_err());
+        // nothing was interned
+        assert_eq!(colors.len(), 3);
+    }
+}
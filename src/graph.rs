@@ -0,0 +1,465 @@
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    vec,
+    vec::Vec,
+};
+use core::{cmp::Reverse, hash::Hash};
+
+use crate::collections::{HashMap, HashSet};
+
+/// Directed graph stored as an adjacency list, nodes can be of any hashable type.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    /// Outgoing edges per node index with their weight
+    edges: Vec<Vec<(usize, u64)>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the node if it doesn't exist yet, returns its index.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.indices.insert(node, index);
+        self.edges.push(Vec::new());
+        index
+    }
+
+    /// Adds a directed, weighted edge, inserting both nodes if necessary.
+    pub fn add_edge(&mut self, from: N, to: N, weight: u64) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push((to, weight));
+    }
+
+    /// Adds an edge in both directions.
+    pub fn add_undirected_edge(&mut self, a: N, b: N, weight: u64) {
+        self.add_edge(a.clone(), b.clone(), weight);
+        self.add_edge(b, a, weight);
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, node: &N) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// Outgoing neighbors of the node with the edge weight, empty for unknown nodes.
+    pub fn neighbors<'a>(&'a self, node: &N) -> impl Iterator<Item = (&'a N, u64)> + 'a {
+        self.indices
+            .get(node)
+            .map(|&index| self.edges[index].as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|&(to, weight)| (&self.nodes[to], weight))
+    }
+
+    /// Number of edges from the start node to every reachable node, ignoring weights.
+    #[must_use]
+    pub fn bfs(&self, start: &N) -> HashMap<N, usize> {
+        bfs(start.clone(), |node| {
+            self.neighbors(node)
+                .map(|(to, _)| to.clone())
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// Shortest weighted distance from the start node to every reachable node.
+    #[must_use]
+    pub fn dijkstra(&self, start: &N) -> HashMap<N, u64> {
+        dijkstra(start.clone(), |node| {
+            self.neighbors(node)
+                .map(|(to, weight)| (to.clone(), weight))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// Shortest weighted path from start to goal guided by an admissible heuristic.
+    pub fn astar(
+        &self,
+        start: &N,
+        goal: &N,
+        heuristic: impl FnMut(&N) -> u64,
+    ) -> Option<(u64, Vec<N>)> {
+        astar(
+            start.clone(),
+            |node| node == goal,
+            |node| {
+                self.neighbors(node)
+                    .map(|(to, weight)| (to.clone(), weight))
+                    .collect::<Vec<_>>()
+            },
+            heuristic,
+        )
+    }
+
+    /// Weakly connected components (edge direction is ignored), in insertion order.
+    #[must_use]
+    pub fn connected_components(&self) -> Vec<Vec<N>> {
+        // union-find over node indices
+        fn find(parents: &mut [usize], index: usize) -> usize {
+            let mut root = index;
+            while parents[root] != root {
+                root = parents[root];
+            }
+            // path compression
+            let mut current = index;
+            while parents[current] != root {
+                let next = parents[current];
+                parents[current] = root;
+                current = next;
+            }
+            root
+        }
+
+        let mut parents = (0..self.nodes.len()).collect::<Vec<_>>();
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, _) in edges {
+                let a = find(&mut parents, from);
+                let b = find(&mut parents, to);
+                if a != b {
+                    parents[b.max(a)] = a.min(b);
+                }
+            }
+        }
+
+        let mut components: Vec<Vec<N>> = Vec::new();
+        let mut component_of_root = HashMap::new();
+        for index in 0..self.nodes.len() {
+            let root = find(&mut parents, index);
+            let component = *component_of_root.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[component].push(self.nodes[index].clone());
+        }
+        components
+    }
+}
+
+/// Breadth-first search over an implicit graph given by a neighbors function,
+/// returns the number of steps from the start to every reachable node.
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut q = VecDeque::from([start]);
+
+    while let Some(current) = q.pop_front() {
+        let steps = distances[&current];
+        for next in neighbors(&current) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), steps + 1);
+                q.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Dijkstra over an implicit graph, neighbors are returned with their edge weight.
+pub fn dijkstra<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> HashMap<N, u64>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    // the heap only stores indices into `nodes` so N doesn't need to be Ord
+    let mut nodes = vec![start.clone()];
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut settled = HashSet::new();
+    let mut heap = BinaryHeap::from([Reverse((0, 0))]);
+
+    while let Some(Reverse((distance, index))) = heap.pop() {
+        let current = nodes[index].clone();
+        if !settled.insert(current.clone()) {
+            continue;
+        }
+        for (next, weight) in neighbors(&current) {
+            let candidate = distance + weight;
+            if distances.get(&next).is_none_or(|&known| candidate < known) {
+                distances.insert(next.clone(), candidate);
+                nodes.push(next);
+                heap.push(Reverse((candidate, nodes.len() - 1)));
+            }
+        }
+    }
+
+    distances
+}
+
+/// A* search over an implicit graph, returns the total cost and the path including
+/// start and goal. With a heuristic of zero this is Dijkstra with early exit.
+pub fn astar<N, I>(
+    start: N,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+) -> Option<(u64, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut costs: HashMap<N, u64> = HashMap::from([(start.clone(), 0)]);
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut settled = HashSet::new();
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    let mut nodes = vec![start];
+
+    while let Some(Reverse((_, index))) = heap.pop() {
+        let current = nodes[index].clone();
+        if !settled.insert(current.clone()) {
+            continue;
+        }
+        let cost = costs[&current];
+
+        if is_goal(&current) {
+            let mut path = vec![current];
+            while let Some(parent) = path.last().and_then(|node| parents.get(node)) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for (next, weight) in neighbors(&current) {
+            let candidate = cost + weight;
+            if costs.get(&next).is_none_or(|&known| candidate < known) {
+                costs.insert(next.clone(), candidate);
+                parents.insert(next.clone(), current.clone());
+                let estimate = candidate + heuristic(&next);
+                nodes.push(next);
+                heap.push(Reverse((estimate, nodes.len() - 1)));
+            }
+        }
+    }
+
+    None
+}
+
+/// The shape of the walk through a functional graph (each node has exactly one successor):
+/// a tail of nodes visited once, followed by a cycle that repeats forever.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Cycle<N> {
+    pub tail: Vec<N>,
+    pub cycle: Vec<N>,
+}
+
+impl<N> Cycle<N> {
+    /// The node reached after the given number of steps from the start.
+    #[must_use]
+    pub fn nth(&self, steps: usize) -> &N {
+        if steps < self.tail.len() {
+            &self.tail[steps]
+        } else {
+            &self.cycle[(steps - self.tail.len()) % self.cycle.len()]
+        }
+    }
+}
+
+/// Follows the successor function from start until a node repeats.
+pub fn detect_cycle<N>(start: N, mut next: impl FnMut(&N) -> N) -> Cycle<N>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut path = Vec::new();
+    let mut seen = HashMap::new();
+    let mut current = start;
+
+    loop {
+        if let Some(&index) = seen.get(&current) {
+            let cycle = path.split_off(index);
+            return Cycle { tail: path, cycle };
+        }
+        seen.insert(current.clone(), path.len());
+        let successor = next(&current);
+        path.push(current);
+        current = successor;
+    }
+}
+
+/// Follows the successor function from start until a goal node is reached, returns the
+/// path including start and goal. Returns `None` if a node has no successor or the walk
+/// enters a cycle without passing a goal.
+pub fn walk_until<N>(
+    start: N,
+    mut next: impl FnMut(&N) -> Option<N>,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut path = Vec::new();
+    let mut seen = HashSet::new();
+    let mut current = start;
+
+    loop {
+        if !seen.insert(current.clone()) {
+            return None;
+        }
+        let goal = is_goal(&current);
+        let successor = if goal { None } else { next(&current) };
+        path.push(current);
+        if goal {
+            return Some(path);
+        }
+        current = successor?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collections::HashMap;
+
+    use super::{bfs, detect_cycle, walk_until, Cycle, Graph};
+
+    fn example_graph() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "b", 7);
+        graph.add_edge("a", "c", 9);
+        graph.add_edge("a", "f", 14);
+        graph.add_edge("b", "c", 10);
+        graph.add_edge("b", "d", 15);
+        graph.add_edge("c", "d", 11);
+        graph.add_edge("c", "f", 2);
+        graph.add_edge("d", "e", 6);
+        graph.add_edge("f", "e", 9);
+        graph.add_edge("x", "y", 1);
+        graph
+    }
+
+    #[test]
+    fn test_add_node() {
+        let mut graph = Graph::new();
+        assert_eq!(graph.add_node('a'), 0);
+        assert_eq!(graph.add_node('b'), 1);
+        assert_eq!(graph.add_node('a'), 0);
+        assert_eq!(graph.len(), 2);
+        assert!(graph.contains(&'b'));
+        assert!(!graph.contains(&'c'));
+        assert_eq!(graph.neighbors(&'c').count(), 0);
+    }
+
+    #[test]
+    fn test_bfs() {
+        let graph = example_graph();
+        let distances = graph.bfs(&"a");
+        assert_eq!(
+            distances,
+            HashMap::from([("a", 0), ("b", 1), ("c", 1), ("f", 1), ("d", 2), ("e", 2)])
+        );
+
+        // implicit graph: numbers reachable by +1 and *2 below 10
+        let distances = bfs(1, |&n| [n + 1, n * 2].into_iter().filter(|&n| n < 10));
+        assert_eq!(distances[&8], 3);
+        assert_eq!(distances[&9], 4);
+        assert_eq!(distances.len(), 9);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = example_graph();
+        let distances = graph.dijkstra(&"a");
+        assert_eq!(
+            distances,
+            HashMap::from([
+                ("a", 0),
+                ("b", 7),
+                ("c", 9),
+                ("d", 20),
+                ("e", 20),
+                ("f", 11)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let graph = example_graph();
+        assert_eq!(
+            graph.astar(&"a", &"e", |_| 0),
+            Some((20, vec!["a", "c", "f", "e"]))
+        );
+        assert_eq!(graph.astar(&"a", &"x", |_| 0), None);
+        assert_eq!(graph.astar(&"a", &"a", |_| 0), Some((0, vec!["a"])));
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let mut graph = example_graph();
+        graph.add_node("z");
+        assert_eq!(
+            graph.connected_components(),
+            vec![
+                vec!["a", "b", "c", "f", "d", "e"],
+                vec!["x", "y"],
+                vec!["z"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_cycle() {
+        let cycle = detect_cycle(0, |&n| if n < 3 { n + 1 } else { (n + 1) % 4 + 1 });
+        assert_eq!(
+            cycle,
+            Cycle {
+                tail: vec![0],
+                cycle: vec![1, 2, 3]
+            }
+        );
+        assert_eq!(*cycle.nth(0), 0);
+        assert_eq!(*cycle.nth(3), 3);
+        assert_eq!(*cycle.nth(4), 1);
+        assert_eq!(*cycle.nth(1000), 1);
+    }
+
+    #[test]
+    fn test_walk_until() {
+        assert_eq!(
+            walk_until(0, |&n| Some(n + 2), |&n| n == 6),
+            Some(vec![0, 2, 4, 6])
+        );
+        // cycle without passing the goal
+        assert_eq!(walk_until(0, |&n| Some((n + 2) % 6), |&n| n == 3), None);
+        // dead end
+        assert_eq!(
+            walk_until(0, |&n| if n < 2 { Some(n + 1) } else { None }, |&n| n == 3),
+            None
+        );
+    }
+}
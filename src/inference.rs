@@ -0,0 +1,268 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive};
+
+use crate::cubes::{ColorId, Game};
+
+/// Composition of a bag, the number of cubes of each color indexed by [`ColorId::index`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Bag(pub Vec<usize>);
+
+impl Bag {
+    #[must_use]
+    pub fn count(&self, color: ColorId) -> usize {
+        self.0.get(color.index()).copied().unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.0.iter().sum()
+    }
+}
+
+/// Smallest bag of the colors that could have produced the draws of the games, the most
+/// cubes of each color drawn at once.
+#[must_use]
+pub fn minimal_bag(games: &[Game], colors: usize) -> Bag {
+    let mut bag = Bag(alloc::vec![0; colors]);
+    for draw in games.iter().flat_map(|game| &game.draws) {
+        for &(color, count) in draw.cubes() {
+            if let Some(min) = bag.0.get_mut(color.index()) {
+                *min = (*min).max(count);
+            }
+        }
+    }
+    bag
+}
+
+/// Bags of the total number of cubes that could have produced the draws of the games, in
+/// lexicographic order.
+#[derive(Debug, Clone)]
+pub struct FeasibleBags {
+    minimum: Bag,
+    /// Cubes on top of the minimum of all but the last color, the last color gets the rest
+    extra: Vec<usize>,
+    spare: usize,
+    done: bool,
+}
+
+impl FeasibleBags {
+    /// Feasible bags of the colors with the total, none if the minimal bag is larger.
+    #[must_use]
+    pub fn new(games: &[Game], colors: usize, total: usize) -> Self {
+        let minimum = minimal_bag(games, colors);
+        let spare = total.checked_sub(minimum.total());
+        Self {
+            extra: alloc::vec![0; colors.saturating_sub(1)],
+            spare: spare.unwrap_or(0),
+            // without colors only the empty bag is left
+            done: spare.is_none() || (colors == 0 && total > 0),
+            minimum,
+        }
+    }
+
+    /// Moves on to the next distribution of the spare cubes, `false` after the last.
+    fn advance(&mut self) -> bool {
+        let mut used = self.extra.iter().sum::<usize>();
+        for extra in self.extra.iter_mut().rev() {
+            if used < self.spare {
+                *extra += 1;
+                return true;
+            }
+            used -= *extra;
+            *extra = 0;
+        }
+        false
+    }
+}
+
+impl Iterator for FeasibleBags {
+    type Item = Bag;
+
+    fn next(&mut self) -> Option<Bag> {
+        if self.done {
+            return None;
+        }
+        let mut bag = self.minimum.clone();
+        let rest = self.spare - self.extra.iter().sum::<usize>();
+        for (count, extra) in bag.0.iter_mut().zip(self.extra.iter().chain([&rest])) {
+            *count += extra;
+        }
+        self.done = !self.advance();
+        Some(bag)
+    }
+}
+
+/// `n` choose `k`.
+fn binomial(n: usize, k: usize) -> BigUint {
+    if k > n {
+        return BigUint::ZERO;
+    }
+    let k = k.min(n - k);
+    (0..k).fold(BigUint::one(), |binomial, i| binomial * (n - i) / (i + 1))
+}
+
+/// Exact probability as a fraction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Probability {
+    pub numerator: BigUint,
+    pub denominator: BigUint,
+}
+
+impl Probability {
+    /// The probability as floating point number, also when numerator and denominator
+    /// don't fit into one.
+    #[must_use]
+    pub fn to_f64(&self) -> f64 {
+        let shift = self.denominator.bits().saturating_sub(1000);
+        let numerator = (&self.numerator >> shift).to_f64().unwrap_or(f64::NAN);
+        let denominator = (&self.denominator >> shift).to_f64().unwrap_or(f64::NAN);
+        numerator / denominator
+    }
+}
+
+/// Probability of the draws of the games from the bag, each draw taken without
+/// replacement and the cubes put back after it, so a draw is multivariate hypergeometric.
+#[must_use]
+pub fn likelihood(games: &[Game], bag: &Bag) -> Probability {
+    let mut probability = Probability {
+        numerator: BigUint::one(),
+        denominator: BigUint::one(),
+    };
+    let total = bag.total();
+    for draw in games.iter().flat_map(|game| &game.draws) {
+        let mut drawn = 0;
+        for &(color, count) in draw.cubes() {
+            probability.numerator *= binomial(bag.count(color), count);
+            drawn += count;
+        }
+        probability.denominator *= binomial(total, drawn);
+    }
+    probability
+}
+
+/// Maximum likelihood estimate of the bag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Estimate {
+    /// All bags of the maximum likelihood, in lexicographic order
+    pub bags: Vec<Bag>,
+    pub likelihood: Probability,
+    /// Number of feasible bags
+    pub feasible: usize,
+}
+
+/// Maximum likelihood estimate of the bag of the total number of cubes that produced the
+/// draws of the games, `None` if no bag could have.
+#[must_use]
+pub fn estimate(games: &[Game], colors: usize, total: usize) -> Option<Estimate> {
+    let mut best: Option<Estimate> = None;
+    for bag in FeasibleBags::new(games, colors, total) {
+        let likelihood = likelihood(games, &bag);
+        match &mut best {
+            None => {
+                best = Some(Estimate {
+                    bags: alloc::vec![bag],
+                    likelihood,
+                    feasible: 1,
+                });
+            }
+            Some(best) => {
+                best.feasible += 1;
+                // the denominator is the same for all bags of the total
+                match likelihood.numerator.cmp(&best.likelihood.numerator) {
+                    core::cmp::Ordering::Less => {}
+                    core::cmp::Ordering::Equal => best.bags.push(bag),
+                    core::cmp::Ordering::Greater => {
+                        best.bags = alloc::vec![bag];
+                        best.likelihood = likelihood;
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use core::slice;
+
+    use num_bigint::BigUint;
+
+    use super::{binomial, estimate, likelihood, minimal_bag, Bag, FeasibleBags};
+    use crate::cubes::parse_games_from_contents;
+
+    const GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+";
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(5, 2), BigUint::from(10u8));
+        assert_eq!(binomial(5, 0), BigUint::from(1u8));
+        assert_eq!(binomial(3, 4), BigUint::ZERO);
+        assert_eq!(
+            binomial(100, 50).to_string(),
+            "100891344545564193334812497256"
+        );
+    }
+
+    #[test]
+    fn test_feasible() {
+        let (colors, games) = parse_games_from_contents(GAMES).unwrap();
+        // blue, red, green in order of appearance
+        assert_eq!(minimal_bag(&games[..1], colors.len()), Bag(vec![6, 4, 2]));
+
+        let bags = FeasibleBags::new(&games[..1], colors.len(), 14).collect::<Vec<_>>();
+        assert_eq!(
+            bags,
+            [
+                Bag(vec![6, 4, 4]),
+                Bag(vec![6, 5, 3]),
+                Bag(vec![6, 6, 2]),
+                Bag(vec![7, 4, 3]),
+                Bag(vec![7, 5, 2]),
+                Bag(vec![8, 4, 2]),
+            ]
+        );
+        assert_eq!(FeasibleBags::new(&games[..1], colors.len(), 12).count(), 1);
+        assert_eq!(FeasibleBags::new(&games[..1], colors.len(), 11).count(), 0);
+        // the 2 extra cubes distributed over 3 colors
+        assert_eq!(FeasibleBags::new(&games, colors.len(), 41).count(), 6);
+        assert_eq!(FeasibleBags::new(&[], 0, 0).count(), 1);
+        assert_eq!(FeasibleBags::new(&[], 0, 1).count(), 0);
+        assert_eq!(
+            FeasibleBags::new(&[], 1, 3).collect::<Vec<_>>(),
+            [Bag(vec![3])]
+        );
+    }
+
+    #[test]
+    fn test_likelihood() {
+        let (mut colors, _) = parse_games_from_contents("").unwrap();
+        let game = crate::cubes::parse_game("Game 1: 1 red; 1 blue, 1 red", &mut colors).unwrap();
+        let bag = Bag(vec![2, 1]);
+        // 2/3 for the red cube, 2/3 for a red and the blue cube
+        let probability = likelihood(slice::from_ref(&game), &bag);
+        assert_eq!(probability.numerator, BigUint::from(4u8));
+        assert_eq!(probability.denominator, BigUint::from(9u8));
+        assert!((probability.to_f64() - 4.0 / 9.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_estimate() {
+        let (colors, games) = parse_games_from_contents(GAMES).unwrap();
+        let estimate_1 = estimate(&games[..1], colors.len(), 14).unwrap();
+        assert_eq!(estimate_1.feasible, 6);
+        assert_eq!(estimate_1.bags, [Bag(vec![7, 4, 3])]);
+
+        // the same bag for all games
+        let joint = estimate(&games, colors.len(), 45).unwrap();
+        assert_eq!(joint.feasible, 28);
+        assert_eq!(joint.bags, [Bag(vec![9, 21, 15])]);
+        assert!((joint.likelihood.to_f64() / 2.074_506_217_806e-21 - 1.0).abs() < 1e-9);
+        assert!(estimate(&games, colors.len(), 38).is_none());
+    }
+}
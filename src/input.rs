@@ -0,0 +1,132 @@
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::examples::split_header;
+
+/// Command line flag that starts the REPL of a day instead of solving the puzzle.
+pub const REPL_FLAG: &str = "--repl";
+
+/// Directory the puzzle inputs are cached in, relative to the working directory.
+pub const INPUT_DIR: &str = "inputs";
+
+/// Path of the cached puzzle input of the day, `<dir>/<year>/day<day>.txt`.
+#[must_use]
+pub fn input_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{day}.txt"))
+}
+
+/// Path of the encrypted copy of the input file, with the extension appended.
+#[cfg(feature = "encrypt")]
+#[must_use]
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(format!(".{}", crate::crypt::EXTENSION));
+    PathBuf::from(path)
+}
+
+/// Reads the cached puzzle input of the day, decrypting the encrypted copy with the
+/// passphrase from the environment if there is no plain one.
+///
+/// # Errors
+///
+/// If neither input can be read or the decryption fails.
+pub fn read_cached_input(dir: &Path, year: u16, day: u8) -> anyhow::Result<String> {
+    let path = input_path(dir, year, day);
+    match fs::read_to_string(&path) {
+        #[cfg(feature = "encrypt")]
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            let Ok(encrypted) = fs::read(encrypted_path(&path)) else {
+                return Err(anyhow::anyhow!("{}: {err}", path.display()));
+            };
+            let input = crate::crypt::decrypt(&encrypted, &crate::crypt::passphrase()?)?;
+            Ok(String::from_utf8(input)?)
+        }
+        result => result.map_err(|err| anyhow::anyhow!("{}: {err}", path.display())),
+    }
+}
+
+/// Whether the REPL of the day was requested on the command line.
+#[must_use]
+pub fn repl_requested() -> bool {
+    std::env::args().skip(1).any(|arg| arg == REPL_FLAG)
+}
+
+/// Whether the command line flag `--<name>` was given to the solution, like `--explain`.
+#[must_use]
+pub fn flag(name: &str) -> bool {
+    std::env::args()
+        .skip(1)
+        .any(|arg| arg.strip_prefix("--") == Some(name))
+}
+
+/// Value of the command line option `--<name>=<value>` of a solution, like
+/// `--overlap=leftmost-first`.
+#[must_use]
+pub fn option(name: &str) -> Option<String> {
+    std::env::args().skip(1).find_map(|arg| {
+        let value = arg
+            .strip_prefix("--")?
+            .strip_prefix(name)?
+            .strip_prefix('=')?;
+        Some(value.to_string())
+    })
+}
+
+/// Reads the puzzle input from the file given as first command line argument that isn't
+/// an option (`-` reads from stdin), falling back to the cached input of the day. The
+/// header of example files is skipped, so they can be passed directly.
+///
+/// # Errors
+///
+/// If the file or stdin can't be read, see [`read_cached_input`] for the cached input.
+pub fn read_input(year: u16, day: u8) -> anyhow::Result<String> {
+    let contents = match input_arg() {
+        Some(path) if path == "-" => {
+            let mut contents = String::new();
+            std::io::stdin().read_to_string(&mut contents)?;
+            contents
+        }
+        Some(path) => fs::read_to_string(path)?,
+        None => read_cached_input(Path::new(INPUT_DIR), year, day)?,
+    };
+    Ok(split_header(&contents).1.to_string())
+}
+
+/// First command line argument that isn't an option.
+fn input_arg() -> Option<String> {
+    std::env::args().skip(1).find(|arg| !arg.starts_with("--"))
+}
+
+/// Like [`read_input`], but streams the file or stdin instead of reading it into memory,
+/// for inputs too large for that.
+///
+/// # Errors
+///
+/// If the file can't be opened or its header can't be read, see [`read_cached_input`] for
+/// the cached input.
+pub fn open_input(year: u16, day: u8) -> anyhow::Result<Box<dyn BufRead>> {
+    let reader: Box<dyn BufRead> = match input_arg() {
+        Some(path) if path == "-" => Box::new(io::stdin().lock()),
+        Some(path) => Box::new(BufReader::new(fs::File::open(path)?)),
+        None => Box::new(io::Cursor::new(
+            read_cached_input(Path::new(INPUT_DIR), year, day)?.into_bytes(),
+        )),
+    };
+    Ok(skip_header(reader)?)
+}
+
+/// Skips the header lines of an example file like [`split_header`].
+fn skip_header(mut reader: Box<dyn BufRead>) -> io::Result<Box<dyn BufRead>> {
+    loop {
+        let mut line = Vec::new();
+        reader.read_until(b'\n', &mut line)?;
+        let content = line.strip_suffix(b"\n").unwrap_or(&line);
+        let content = content.strip_suffix(b"\r").unwrap_or(content);
+        if !(content.starts_with(b"# ") || content == b"#") {
+            return Ok(Box::new(io::Cursor::new(line).chain(reader)));
+        }
+    }
+}
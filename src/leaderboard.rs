@@ -0,0 +1,363 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use serde::Deserialize;
+
+use crate::puzzle::DAYS;
+
+/// Environment variable with the session cookie used to fetch private leaderboards.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+#[derive(Debug, Deserialize)]
+struct Export {
+    event: String,
+    members: BTreeMap<String, ExportMember>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExportMember {
+    id: u64,
+    name: Option<String>,
+    #[serde(default)]
+    local_score: u64,
+    #[serde(default)]
+    completion_day_level: BTreeMap<u8, BTreeMap<u8, ExportStar>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExportStar {
+    get_star_ts: i64,
+}
+
+/// Member of a private leaderboard with the timestamps of their stars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    /// Local score as exported by the leaderboard
+    pub reported_score: u64,
+    /// Unix timestamp of each star by day and part
+    pub stars: BTreeMap<(u8, u8), i64>,
+}
+
+impl Member {
+    /// Seconds between the stars of part 1 and part 2 of the day, if both were got.
+    #[must_use]
+    pub fn part_delta(&self, day: u8) -> Option<i64> {
+        Some(self.stars.get(&(day, 2))? - self.stars.get(&(day, 1))?)
+    }
+
+    /// Timestamp of the last star got until the end of the day.
+    fn last_star(&self, day: u8) -> Option<i64> {
+        self.stars
+            .iter()
+            .filter(|((star_day, _), _)| *star_day <= day)
+            .map(|(_, timestamp)| *timestamp)
+            .max()
+    }
+}
+
+/// A private leaderboard, the members ordered by id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: Vec<Member>,
+}
+
+/// URL of the JSON export of the private leaderboard on adventofcode.com.
+#[must_use]
+pub fn leaderboard_url(year: u16, id: u64) -> String {
+    format!("https://adventofcode.com/{year}/leaderboard/private/view/{id}.json")
+}
+
+/// Fetches the leaderboard JSON from the URL with the session cookie of a member.
+///
+/// # Errors
+///
+/// If the request fails.
+pub fn fetch(url: &str, session: &str) -> anyhow::Result<String> {
+    Ok(ureq::get(url)
+        .set("Cookie", &format!("session={session}"))
+        .set(
+            "User-Agent",
+            concat!(env!("CARGO_PKG_NAME"), " leaderboard"),
+        )
+        .call()?
+        .into_string()?)
+}
+
+/// Seconds as `45s`, `12m05s`, `3h07m` or `2d04h`.
+fn format_delta(seconds: i64) -> String {
+    match seconds {
+        ..60 => format!("{seconds}s"),
+        60..3600 => format!("{}m{:02}s", seconds / 60, seconds % 60),
+        3600..86400 => format!("{}h{:02}m", seconds / 3600, seconds % 3600 / 60),
+        _ => format!("{}d{:02}h", seconds / 86400, seconds % 86400 / 3600),
+    }
+}
+
+/// Letter marking the member in the rank chart.
+fn marker(index: usize) -> char {
+    let letters = ('A'..='Z').chain('a'..='z').collect::<Vec<_>>();
+    letters.get(index).copied().unwrap_or('?')
+}
+
+impl Leaderboard {
+    /// Parses the JSON export of a private leaderboard.
+    ///
+    /// # Errors
+    ///
+    /// If the JSON is not a leaderboard export.
+    pub fn parse(json: &str) -> anyhow::Result<Self> {
+        let export = serde_json::from_str::<Export>(json)?;
+        let mut members = export
+            .members
+            .into_values()
+            .map(|member| Member {
+                id: member.id,
+                name: member
+                    .name
+                    .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+                reported_score: member.local_score,
+                stars: member
+                    .completion_day_level
+                    .into_iter()
+                    .flat_map(|(day, parts)| {
+                        parts
+                            .into_iter()
+                            .map(move |(part, star)| ((day, part), star.get_star_ts))
+                    })
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+        members.sort_by_key(|member| member.id);
+        Ok(Self {
+            event: export.event,
+            members,
+        })
+    }
+
+    /// Days any member got a star on.
+    #[must_use]
+    pub fn days(&self) -> Vec<u8> {
+        DAYS.filter(|day| {
+            self.members
+                .iter()
+                .any(|member| member.stars.keys().any(|(star_day, _)| star_day == day))
+        })
+        .collect()
+    }
+
+    /// Local scores of the members at the end of each day with stars, recomputed from the
+    /// star timestamps: the first member to get a star scores one point per member, the
+    /// second one point less and so on.
+    #[must_use]
+    pub fn scores_by_day(&self) -> Vec<(u8, Vec<u64>)> {
+        let points = self.members.len() as u64;
+        let mut scores = vec![0; self.members.len()];
+        let mut by_day = Vec::new();
+
+        for day in self.days() {
+            for part in 1..=2 {
+                let mut finishers = self
+                    .members
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, member)| Some((*member.stars.get(&(day, part))?, index)))
+                    .collect::<Vec<_>>();
+                finishers.sort_unstable();
+                for (rank, (_, index)) in (0..).zip(finishers) {
+                    scores[index] += points - rank;
+                }
+            }
+            by_day.push((day, scores.clone()));
+        }
+        by_day
+    }
+
+    /// Member indices from first to last by score at the end of the day, ties going to the
+    /// member who got their last star first.
+    fn ranking(&self, day: u8, scores: &[u64]) -> Vec<usize> {
+        let mut ranking = (0..self.members.len()).collect::<Vec<_>>();
+        ranking.sort_by_key(|&index| {
+            let last_star = self.members[index].last_star(day);
+            (
+                std::cmp::Reverse(scores[index]),
+                last_star.is_none(),
+                last_star,
+                self.members[index].id,
+            )
+        });
+        ranking
+    }
+
+    /// Stars and local scores, the time between part 1 and part 2 of each day and a chart of
+    /// the ranks over the days.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        let days = self.days();
+        let scores_by_day = self.scores_by_day();
+        let scores = scores_by_day
+            .last()
+            .map_or_else(|| vec![0; self.members.len()], |(_, scores)| scores.clone());
+        let last_day = days.last().copied().unwrap_or(0);
+        let ranking = self.ranking(last_day, &scores);
+        let width = self
+            .members
+            .iter()
+            .map(|member| member.name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("Member".len());
+
+        let _ = writeln!(
+            text,
+            "Leaderboard {}, {} members\n",
+            self.event,
+            self.members.len()
+        );
+        let _ = writeln!(text, "Rank  {:width$}  Stars  Score", "Member");
+        for (rank, &index) in ranking.iter().enumerate() {
+            let member = &self.members[index];
+            let _ = write!(
+                text,
+                "{:>4}  {:width$}  {:>5}  {:>5}",
+                rank + 1,
+                member.name,
+                member.stars.len(),
+                scores[index]
+            );
+            if member.reported_score != scores[index] {
+                let _ = write!(text, " (reported {})", member.reported_score);
+            }
+            text.push('\n');
+        }
+
+        let _ = writeln!(text, "\nTime between part 1 and part 2\n");
+        let _ = write!(text, "{:width$}", "Member");
+        for day in &days {
+            let _ = write!(text, "  {:>6}", format!("Day {day}"));
+        }
+        text.push('\n');
+        for member in ranking
+            .iter()
+            .map(|&index| &self.members[index])
+            .filter(|member| !member.stars.is_empty())
+        {
+            let _ = write!(text, "{:width$}", member.name);
+            for &day in &days {
+                let delta = match member.part_delta(day) {
+                    Some(delta) => format_delta(delta),
+                    None if member.stars.contains_key(&(day, 1)) => "-".to_string(),
+                    None => String::new(),
+                };
+                let _ = write!(text, "  {delta:>6}");
+            }
+            text.push('\n');
+        }
+
+        let _ = writeln!(text, "\nRank over days\n");
+        let _ = write!(text, "Rank");
+        for day in &days {
+            let _ = write!(text, " {day:>2}");
+        }
+        text.push('\n');
+        let rankings = scores_by_day
+            .iter()
+            .map(|(day, scores)| self.ranking(*day, scores))
+            .collect::<Vec<_>>();
+        for rank in 0..self.members.len() {
+            let _ = write!(text, "{:>4}", rank + 1);
+            for ranking in &rankings {
+                let _ = write!(text, "  {}", marker(ranking[rank]));
+            }
+            text.push('\n');
+        }
+        text.push('\n');
+        for (index, member) in self.members.iter().enumerate() {
+            let _ = writeln!(text, "{} {}", marker(index), member.name);
+        }
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_delta, Leaderboard};
+
+    const EXPORT: &str = r#"{
+        "owner_id": 1,
+        "event": "2023",
+        "members": {
+            "1": {
+                "id": 1, "name": "Ada", "stars": 3, "local_score": 11,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 100, "star_index": 0},
+                          "2": {"get_star_ts": 400, "star_index": 1}},
+                    "2": {"1": {"get_star_ts": 90000, "star_index": 2}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 4, "local_score": 12,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 200, "star_index": 0},
+                          "2": {"get_star_ts": 260, "star_index": 1}},
+                    "2": {"1": {"get_star_ts": 86500, "star_index": 2},
+                          "2": {"get_star_ts": 94000, "star_index": 3}}
+                }
+            },
+            "3": {
+                "id": 3, "name": "Cy", "stars": 0, "local_score": 0,
+                "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_scores() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+        assert_eq!(leaderboard.members[1].name, "(anonymous user #2)");
+        assert_eq!(leaderboard.days(), vec![1, 2]);
+        assert_eq!(
+            leaderboard.scores_by_day(),
+            vec![(1, vec![5, 5, 0]), (2, vec![7, 11, 0])]
+        );
+        assert_eq!(leaderboard.members[0].part_delta(1), Some(300));
+        assert_eq!(leaderboard.members[0].part_delta(2), None);
+    }
+
+    #[test]
+    fn test_to_text() {
+        let text = Leaderboard::parse(EXPORT).unwrap().to_text();
+        assert_eq!(
+            text,
+            "Leaderboard 2023, 3 members\n\n\
+             Rank  Member               Stars  Score\n   \
+                1  (anonymous user #2)      4     11 (reported 12)\n   \
+                2  Ada                      3      7 (reported 11)\n   \
+                3  Cy                       0      0\n\n\
+             Time between part 1 and part 2\n\n\
+             Member                Day 1   Day 2\n\
+             (anonymous user #2)   1m00s   2h05m\n\
+             Ada                   5m00s       -\n\
+             \n\
+             Rank over days\n\n\
+             Rank  1  2\n   \
+                1  B  B\n   \
+                2  A  A\n   \
+                3  C  C\n\n\
+             A Ada\n\
+             B (anonymous user #2)\n\
+             C Cy\n"
+        );
+    }
+
+    #[test]
+    fn test_format_delta() {
+        assert_eq!(format_delta(45), "45s");
+        assert_eq!(format_delta(725), "12m05s");
+        assert_eq!(format_delta(3 * 3600 + 7 * 60), "3h07m");
+        assert_eq!(format_delta(2 * 86400 + 4 * 3600), "2d04h");
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(clippy::pedantic)]
+//! Shared algorithms and helpers used across the daily puzzle solutions.
+//!
+//! Without the `std` feature only the solver core is built, `no_std` with `alloc`.
+
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "hashbrown")))]
+compile_error!("Without std the hash maps and sets need the `hashbrown` feature!");
+
+pub mod answer;
+#[cfg(feature = "std")]
+pub mod answers;
+pub mod calibration;
+pub mod checked;
+pub mod collections;
+#[cfg(feature = "encrypt")]
+pub mod crypt;
+pub mod cubes;
+#[cfg(feature = "std")]
+pub mod examples;
+pub mod game_query;
+pub mod graph;
+pub mod inference;
+#[cfg(feature = "std")]
+pub mod input;
+#[cfg(feature = "leaderboard")]
+pub mod leaderboard;
+pub mod math;
+pub mod puzzle;
+pub mod ranges;
+#[cfg(feature = "std")]
+pub mod repl;
+#[cfg(feature = "std")]
+pub mod report;
+#[cfg(feature = "std")]
+pub mod runner;
+#[cfg(feature = "std")]
+pub mod scaffold;
+#[cfg(feature = "serve")]
+pub mod serve;
+#[cfg(feature = "std")]
+pub mod snapshot;
+pub mod vocabulary;
+#[cfg(feature = "std")]
+pub mod watch;
+pub mod year2023;
@@ -0,0 +1,293 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use num_traits::{PrimInt, Signed};
+
+/// Absolute value that also works for unsigned types, `None` for `T::MIN` of signed types.
+fn abs<T: PrimInt>(value: T) -> Option<T> {
+    if value < T::zero() {
+        T::zero().checked_sub(&value)
+    } else {
+        Some(value)
+    }
+}
+
+/// Remainder that is always in `0..modulus` for a positive modulus.
+fn modulo<T: PrimInt>(value: T, modulus: T) -> T {
+    let remainder = value % modulus;
+    if remainder < T::zero() {
+        remainder + modulus
+    } else {
+        remainder
+    }
+}
+
+/// Greatest common divisor, always non-negative. `None` if it overflows `T`, which only
+/// happens for `T::MIN` and zero or `T::MIN` itself.
+pub fn gcd<T: PrimInt>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    while b != T::zero() {
+        // the remainder of `T::MIN / -1` is zero, but computing it overflows
+        let remainder = if a.checked_div(&b).is_some() {
+            a % b
+        } else {
+            T::zero()
+        };
+        (a, b) = (b, remainder);
+    }
+    abs(a)
+}
+
+/// Least common multiple, always non-negative and zero if either number is zero. `None` if
+/// the result overflows `T`.
+pub fn lcm<T: PrimInt>(a: T, b: T) -> Option<T> {
+    if a == T::zero() || b == T::zero() {
+        Some(T::zero())
+    } else {
+        abs((a / gcd(a, b)?).checked_mul(&b)?)
+    }
+}
+
+/// Least common multiple of all the numbers, one for an empty list. `None` if the result
+/// overflows `T`.
+pub fn lcm_list<T: PrimInt>(numbers: &[T]) -> Option<T> {
+    numbers.iter().try_fold(T::one(), |l, &n| lcm(l, n))
+}
+
+/// Extended euclidean algorithm, returns `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_remainder, mut remainder) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while remainder != T::zero() {
+        let quotient = old_remainder / remainder;
+        (old_remainder, remainder) = (remainder, old_remainder - quotient * remainder);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_remainder < T::zero() {
+        (-old_remainder, -old_x, -old_y)
+    } else {
+        (old_remainder, old_x, old_y)
+    }
+}
+
+/// Multiplicative inverse of `a` modulo `modulus`, if `a` and `modulus` are coprime.
+pub fn mod_inverse<T: PrimInt + Signed>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(modulo(a, modulus), modulus);
+    (g == T::one()).then(|| modulo(x, modulus))
+}
+
+/// Chinese remainder theorem for congruences `x ≡ residue (mod modulus)`, the moduli
+/// don't need to be coprime. Returns the smallest non-negative solution together with the
+/// combined modulus, `None` if the congruences contradict each other or the result overflows.
+pub fn crt<T: PrimInt + Signed>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::zero(), T::one()), |(a1, m1), &(a2, m2)| {
+            if m2 <= T::zero() {
+                return None;
+            }
+            let a2 = modulo(a2, m2);
+            let (g, p, _) = extended_gcd(m1, m2);
+            let difference = a2 - a1;
+            if difference % g != T::zero() {
+                return None;
+            }
+
+            // m1 * k ≡ a2 - a1 (mod m2) solved for k in 0..m2/g
+            let m2 = m2 / g;
+            let k = modulo(modulo(difference / g, m2).checked_mul(&modulo(p, m2))?, m2);
+            let modulus = m1.checked_mul(&m2)?;
+            let x = a1.checked_add(&m1.checked_mul(&k)?)?;
+            Some((modulo(x, modulus), modulus))
+        })
+}
+
+/// Integer square root, the largest `r` with `r * r <= n`.
+///
+/// # Panics
+///
+/// If n is negative.
+pub fn isqrt<T: PrimInt>(n: T) -> T {
+    assert!(n >= T::zero(), "Square root of negative number!");
+    let two = T::one() + T::one();
+    if n < two {
+        return n;
+    }
+
+    // newtons method, starting above the root and decreasing monotonically
+    let mut x = n / two + T::one();
+    loop {
+        let y = (x + n / x) / two;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// All integers `x` with `x² + b·x + c < 0`, that is strictly between the two real roots.
+///
+/// Computed exactly without floating point: `x` is inside iff `(2x + b)² < b² - 4c`.
+#[must_use]
+pub fn quadratic_negative_range(b: i64, c: i64) -> Option<RangeInclusive<i64>> {
+    let b = i128::from(b);
+    let discriminant = b * b - 4 * i128::from(c);
+    if discriminant <= 0 {
+        return None;
+    }
+
+    // largest s with s² < discriminant, then |2x + b| <= s
+    let s = isqrt(discriminant - 1);
+    let lower = -(b + s).div_euclid(2);
+    let upper = (s - b).div_euclid(2);
+
+    (lower <= upper)
+        .then(|| Some(i64::try_from(lower).ok()?..=i64::try_from(upper).ok()?))
+        .flatten()
+}
+
+/// Rows of repeated differences of the sequence, starting with the first differences and
+/// stopping before the row of all zeros (or when the sequence is exhausted).
+pub fn differences<T: PrimInt + Signed>(sequence: &[T]) -> Vec<Vec<T>> {
+    let mut rows = Vec::new();
+    let mut current = sequence.to_vec();
+    loop {
+        current = current
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect::<Vec<_>>();
+        if current.iter().all(|n| *n == T::zero()) {
+            break;
+        }
+        rows.push(current.clone());
+    }
+    rows
+}
+
+/// The next value of a polynomial sequence, extrapolated with finite differences.
+pub fn extrapolate_next<T: PrimInt + Signed>(sequence: &[T]) -> Option<T> {
+    let last = *sequence.last()?;
+    Some(
+        differences(sequence)
+            .iter()
+            .filter_map(|row| row.last())
+            .fold(last, |acc, &n| acc + n),
+    )
+}
+
+/// The value before the first of a polynomial sequence, extrapolated with finite differences.
+pub fn extrapolate_previous<T: PrimInt + Signed>(sequence: &[T]) -> Option<T> {
+    let first = *sequence.first()?;
+    let below = differences(sequence)
+        .iter()
+        .rev()
+        .filter_map(|row| row.first())
+        .fold(T::zero(), |acc, &n| n - acc);
+    Some(first - below)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        crt, differences, extended_gcd, extrapolate_next, extrapolate_previous, gcd, isqrt, lcm,
+        lcm_list, mod_inverse, quadratic_negative_range,
+    };
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12u8, 18), Some(6));
+        assert_eq!(gcd(-12i32, 18), Some(6));
+        assert_eq!(gcd(0u64, 5), Some(5));
+        assert_eq!(gcd(0u64, 0), Some(0));
+        assert_eq!(lcm(4u32, 6), Some(12));
+        assert_eq!(lcm(-4i64, 6), Some(12));
+        assert_eq!(lcm(0usize, 6), Some(0));
+        assert_eq!(lcm_list(&[2u64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_list::<u64>(&[]), Some(1));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(lcm(200u8, 3), None);
+        assert_eq!(lcm(20u8, 3), Some(60));
+        assert_eq!(lcm_list(&[16u8, 15]), Some(240));
+        assert_eq!(lcm_list(&[16u8, 15, 7]), None);
+        assert_eq!(lcm(i64::MIN, 3), None);
+
+        // the absolute value of `MIN` doesn't fit
+        assert_eq!(gcd(i64::MIN, 0), None);
+        assert_eq!(gcd(0, i64::MIN), None);
+        assert_eq!(gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(gcd(i64::MIN, 6), Some(2));
+        assert_eq!(gcd(i64::MIN, -1), Some(1));
+        assert_eq!(gcd(i8::MIN, i8::MIN + 1), Some(1));
+        assert_eq!(lcm(i64::MIN, 1), None);
+        assert_eq!(lcm(i64::MIN / 2, 2), Some(i64::MAX / 2 + 1));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240i64, 46), (46, 240), (-7, 3), (0, 5), (17, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(Some(g), gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3i32, 11), Some(4));
+        assert_eq!(mod_inverse(-3i32, 11), Some(7));
+        assert_eq!(mod_inverse(4i32, 8), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // moduli that are not coprime
+        assert_eq!(crt(&[(2i64, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1i64, 5)]), Some((4, 5)));
+        assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(0i8, 11), (0, 13)]), None);
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for n in 0u64..10_000 {
+            let r = isqrt(n);
+            assert!(r * r <= n && (r + 1) * (r + 1) > n, "isqrt({n}) = {r}");
+        }
+        assert_eq!(isqrt(u64::MAX), u64::from(u32::MAX));
+        assert_eq!(isqrt(u8::MAX), 15);
+    }
+
+    #[test]
+    fn test_quadratic_negative_range() {
+        // button presses beating the record: x * (time - x) > record
+        assert_eq!(quadratic_negative_range(-7, 9), Some(2..=5));
+        assert_eq!(quadratic_negative_range(-15, 40), Some(4..=11));
+        // the roots 10 and 20 themselves only tie the record
+        assert_eq!(quadratic_negative_range(-30, 200), Some(11..=19));
+        assert_eq!(quadratic_negative_range(-4, 4), None);
+        assert_eq!(quadratic_negative_range(0, 1), None);
+        assert_eq!(quadratic_negative_range(0, -1), Some(0..=0));
+        assert_eq!(quadratic_negative_range(1, -2), Some(-1..=0));
+    }
+
+    #[test]
+    fn test_differences() {
+        assert_eq!(
+            differences(&[0i64, 3, 6, 9, 12, 15]),
+            vec![vec![3, 3, 3, 3, 3]]
+        );
+        assert_eq!(
+            differences(&[1i64, 3, 6, 10, 15, 21]),
+            vec![vec![2, 3, 4, 5, 6], vec![1, 1, 1, 1]]
+        );
+        assert_eq!(differences(&[4i32, 5, 3]), vec![vec![1, -2], vec![-3]]);
+        assert_eq!(extrapolate_next(&[10i64, 13, 16, 21, 30, 45]), Some(68));
+        assert_eq!(extrapolate_previous(&[10i64, 13, 16, 21, 30, 45]), Some(5));
+        assert_eq!(extrapolate_next::<i64>(&[]), None);
+    }
+}
@@ -0,0 +1,89 @@
+use alloc::{format, string::String};
+use core::fmt;
+
+/// Year assumed when none is given, the year these tools were started with.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// The first year of Advent of Code.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Days of an Advent of Code year.
+pub const DAYS: core::ops::RangeInclusive<u8> = 1..=25;
+
+/// One part of the puzzle of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+}
+
+impl Puzzle {
+    #[must_use]
+    pub fn new(year: u16, day: u8, part: u8) -> Self {
+        Self { year, day, part }
+    }
+
+    /// Name of the binary solving the puzzle.
+    #[must_use]
+    pub fn bin_name(&self) -> String {
+        format!("year{}_day{}_part{}", self.year, self.day, self.part)
+    }
+
+    /// The puzzle solved by the binary of that name, see [`Puzzle::bin_name`].
+    #[must_use]
+    pub fn from_bin_name(name: &str) -> Option<Self> {
+        let (year, name) = name.strip_prefix("year")?.split_once("_day")?;
+        let (day, part) = name.split_once("_part")?;
+        Some(Self::new(
+            year.parse().ok()?,
+            day.parse().ok()?,
+            part.parse().ok()?,
+        ))
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} Day {} Part {}", self.year, self.day, self.part)
+    }
+}
+
+/// Checks that the year and day name a published puzzle day.
+///
+/// # Errors
+///
+/// If the year is before the first Advent of Code or the day isn't in December 1 to 25.
+pub fn validate_day(year: u16, day: u8) -> anyhow::Result<()> {
+    if year < FIRST_YEAR {
+        return Err(anyhow::anyhow!("Invalid Year {year}!"));
+    }
+    if !DAYS.contains(&day) {
+        return Err(anyhow::anyhow!("Invalid Day {day}!"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_day, Puzzle};
+
+    #[test]
+    fn test_bin_name() {
+        let puzzle = Puzzle::new(2023, 12, 2);
+        assert_eq!(puzzle.bin_name(), "year2023_day12_part2");
+        assert_eq!(Puzzle::from_bin_name("year2023_day12_part2"), Some(puzzle));
+        assert_eq!(Puzzle::from_bin_name("day12_part2"), None);
+        assert_eq!(Puzzle::from_bin_name("year2023_day12_partx"), None);
+        assert_eq!(puzzle.to_string(), "2023 Day 12 Part 2");
+    }
+
+    #[test]
+    fn test_validate_day() {
+        assert!(validate_day(2015, 1).is_ok());
+        assert!(validate_day(2024, 25).is_ok());
+        assert!(validate_day(2014, 1).is_err());
+        assert!(validate_day(2023, 0).is_err());
+        assert!(validate_day(2023, 26).is_err());
+    }
+}
@@ -0,0 +1,483 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Set of values stored as sorted, disjoint and non-adjacent half-open ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Ord + Copy> RangeSet<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorts the ranges, drops empty ones and merges overlapping or adjacent ones.
+    fn normalize(mut ranges: Vec<Range<T>>) -> Vec<Range<T>> {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_by_key(|range| range.start);
+
+        let mut normalized: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match normalized.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => normalized.push(range),
+            }
+        }
+        normalized
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = core::mem::take(&mut self.ranges);
+        ranges.push(range);
+        self.ranges = Self::normalize(ranges);
+    }
+
+    #[must_use]
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Range<T>> {
+        self.ranges.iter()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    /// The smallest value in the set.
+    #[must_use]
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .cloned()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // advance whichever range ends first
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// All values in this set that are not in the other set.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            // skip everything of other that ends before the current range
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < range.end {
+                let cut = &other.ranges[k];
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl RangeSet<u64> {
+    /// Number of values in the set.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self {
+            ranges: Self::normalize(iter.into_iter().collect()),
+        }
+    }
+}
+
+/// Piecewise offset mapping of values: each segment maps its source range onto the
+/// range of the same length starting at its destination, every value outside of all
+/// segments maps to itself. The domain is `0..u64::MAX`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    /// Disjoint source ranges sorted by start, with the destination of the range start
+    segments: Vec<(Range<u64>, u64)>,
+}
+
+impl RangeMap {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a segment mapping `source` to `destination..destination + source.len()`.
+    ///
+    /// # Errors
+    ///
+    /// If the source range overlaps an existing segment or the destination overflows.
+    pub fn insert(&mut self, source: Range<u64>, destination: u64) -> anyhow::Result<()> {
+        if source.start >= source.end {
+            return Ok(());
+        }
+        destination
+            .checked_add(source.end - source.start)
+            .ok_or(anyhow::anyhow!("Destination range overflows!"))?;
+
+        let index = self
+            .segments
+            .partition_point(|(range, _)| range.start < source.start);
+        let overlaps_previous = index > 0 && self.segments[index - 1].0.end > source.start;
+        let overlaps_next = self
+            .segments
+            .get(index)
+            .is_some_and(|(range, _)| range.start < source.end);
+        if overlaps_previous || overlaps_next {
+            return Err(anyhow::anyhow!("Overlapping source ranges!"));
+        }
+
+        self.segments.insert(index, (source, destination));
+        Ok(())
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item = &(Range<u64>, u64)> {
+        self.segments.iter()
+    }
+
+    #[must_use]
+    pub fn apply(&self, value: u64) -> u64 {
+        let index = self
+            .segments
+            .partition_point(|(range, _)| range.end <= value);
+        match self.segments.get(index) {
+            Some((range, destination)) if range.start <= value => {
+                destination + (value - range.start)
+            }
+            _ => value,
+        }
+    }
+
+    /// Splits the range along the segment borders, returns every part with the
+    /// destination its start is mapped to.
+    fn split(&self, range: Range<u64>) -> Vec<(Range<u64>, u64)> {
+        let mut parts = Vec::new();
+        let mut start = range.start;
+        let index = self
+            .segments
+            .partition_point(|(source, _)| source.end <= start);
+
+        for (source, destination) in &self.segments[index..] {
+            if start >= range.end || source.start >= range.end {
+                break;
+            }
+            // identity for everything before the matching segment
+            if source.start > start {
+                parts.push((start..source.start, start));
+                start = source.start;
+            }
+            let end = range.end.min(source.end);
+            parts.push((start..end, destination + (start - source.start)));
+            start = end;
+        }
+
+        if start < range.end {
+            parts.push((start..range.end, start));
+        }
+        parts
+    }
+
+    /// All values the range is mapped to.
+    #[must_use]
+    pub fn apply_range(&self, range: Range<u64>) -> RangeSet<u64> {
+        self.split(range)
+            .into_iter()
+            .map(|(part, destination)| destination..destination + (part.end - part.start))
+            .collect()
+    }
+
+    /// All values the set is mapped to.
+    #[must_use]
+    pub fn apply_set(&self, set: &RangeSet<u64>) -> RangeSet<u64> {
+        set.iter()
+            .flat_map(|range| self.split(range.clone()))
+            .map(|(part, destination)| destination..destination + (part.end - part.start))
+            .collect()
+    }
+
+    /// Builds a map from segments sorted by source, dropping identity segments and
+    /// merging segments that continue each other.
+    fn from_sorted_segments(segments: impl IntoIterator<Item = (Range<u64>, u64)>) -> Self {
+        let mut merged: Vec<(Range<u64>, u64)> = Vec::new();
+        for (source, destination) in segments {
+            if source.start >= source.end || source.start == destination {
+                continue;
+            }
+            match merged.last_mut() {
+                Some((last, last_destination))
+                    if last.end == source.start
+                        && *last_destination + (last.end - last.start) == destination =>
+                {
+                    last.end = source.end;
+                }
+                _ => merged.push((source, destination)),
+            }
+        }
+        Self { segments: merged }
+    }
+
+    /// The map that applies this map first and then the next map.
+    #[must_use]
+    pub fn compose(&self, next: &Self) -> Self {
+        // every part of the domain, including the identity parts between segments
+        let pieces = self.split(0..u64::MAX);
+
+        Self::from_sorted_segments(pieces.into_iter().flat_map(|(source, destination)| {
+            let image = destination..destination + (source.end - source.start);
+            next.split(image)
+                .into_iter()
+                .map(move |(part, next_destination)| {
+                    let start = source.start + (part.start - destination);
+                    (start..start + (part.end - part.start), next_destination)
+                })
+        }))
+    }
+
+    /// The inverse map, `None` if the map is not a bijection (two values map to the same value).
+    #[must_use]
+    pub fn invert(&self) -> Option<Self> {
+        let sources = self
+            .segments
+            .iter()
+            .map(|(source, _)| source.clone())
+            .collect::<RangeSet<u64>>();
+        let images = self
+            .segments
+            .iter()
+            .map(|(source, destination)| *destination..destination + (source.end - source.start))
+            .collect::<RangeSet<u64>>();
+
+        // the images have to exactly cover the sources (which also makes them disjoint),
+        // values outside of the sources already map to themselves
+        if images != sources {
+            return None;
+        }
+
+        let mut segments = self
+            .segments
+            .iter()
+            .map(|(source, destination)| {
+                (
+                    *destination..destination + (source.end - source.start),
+                    source.start,
+                )
+            })
+            .collect::<Vec<_>>();
+        segments.sort_by_key(|(source, _)| source.start);
+        Some(Self::from_sorted_segments(segments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, ops::Range};
+
+    use proptest::{collection::vec, prelude::*};
+
+    use super::{RangeMap, RangeSet};
+
+    const DOMAIN: u64 = 64;
+
+    fn values(set: &RangeSet<u64>) -> BTreeSet<u64> {
+        set.iter().flat_map(Clone::clone).collect()
+    }
+
+    fn range_strategy() -> impl Strategy<Value = Range<u64>> {
+        (0..DOMAIN, 0..16u64).prop_map(|(start, length)| start..start + length)
+    }
+
+    fn set_strategy() -> impl Strategy<Value = RangeSet<u64>> {
+        vec(range_strategy(), 0..6).prop_map(|ranges| ranges.into_iter().collect())
+    }
+
+    fn map_strategy() -> impl Strategy<Value = RangeMap> {
+        vec((range_strategy(), 0..DOMAIN), 0..6).prop_map(|segments| {
+            let mut map = RangeMap::new();
+            for (source, destination) in segments {
+                // overlapping segments are rejected, skip them
+                let _ = map.insert(source, destination);
+            }
+            map
+        })
+    }
+
+    fn permutation_strategy() -> impl Strategy<Value = RangeMap> {
+        // swapping two disjoint blocks of the same length is a bijection
+        (0..DOMAIN, 0..DOMAIN, 1..8u64).prop_map(|(a, b, length)| {
+            let (a, b) = (a.min(b), a.max(b));
+            let length = length.min(b - a).max(1);
+            let mut map = RangeMap::new();
+            if b - a >= length {
+                map.insert(a..a + length, b).unwrap();
+                map.insert(b..b + length, a).unwrap();
+            }
+            map
+        })
+    }
+
+    #[test]
+    fn test_normalize() {
+        let set = [5..8, 1..3, 3..4, 7..10, 12..12]
+            .into_iter()
+            .collect::<RangeSet<u64>>();
+        assert_eq!(set.ranges(), &[1..4, 5..10]);
+        assert_eq!(set.count(), 8);
+        assert_eq!(set.min(), Some(1));
+        assert!(set.contains(9));
+        assert!(!set.contains(4));
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_set_operations() {
+        let a = [0..10, 20..30].into_iter().collect::<RangeSet<u64>>();
+        let b = [5..25].into_iter().collect::<RangeSet<u64>>();
+        assert_eq!(a.union(&b).ranges(), &[0..30]);
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..30]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20]);
+    }
+
+    #[test]
+    fn test_map_apply() {
+        let mut map = RangeMap::new();
+        map.insert(98..100, 50).unwrap();
+        map.insert(50..98, 52).unwrap();
+        assert!(map.insert(60..61, 0).is_err());
+        assert!(map.insert(u64::MAX - 1..u64::MAX, 5).is_ok());
+        assert!(map.insert(0..2, u64::MAX - 1).is_err());
+
+        assert_eq!(map.apply(79), 81);
+        assert_eq!(map.apply(14), 14);
+        assert_eq!(map.apply(98), 50);
+        assert_eq!(map.apply(99), 51);
+        assert_eq!(map.apply(100), 100);
+        assert_eq!(map.apply_range(96..102).ranges(), &[50..52, 98..102]);
+    }
+
+    #[test]
+    fn test_map_compose_invert() {
+        let mut first = RangeMap::new();
+        first.insert(0..10, 100).unwrap();
+        let mut second = RangeMap::new();
+        second.insert(100..105, 0).unwrap();
+        let composed = first.compose(&second);
+        assert_eq!(
+            composed.segments().cloned().collect::<Vec<_>>(),
+            [(5..10, 105), (100..105, 0)]
+        );
+        assert_eq!(first.invert(), None);
+
+        let mut swap = RangeMap::new();
+        swap.insert(0..5, 10).unwrap();
+        swap.insert(10..15, 0).unwrap();
+        assert_eq!(swap.compose(&swap.invert().unwrap()), RangeMap::new());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_set_operations_match_model(a in set_strategy(), b in set_strategy()) {
+            let (va, vb) = (values(&a), values(&b));
+            prop_assert_eq!(values(&a.union(&b)), &va | &vb);
+            prop_assert_eq!(values(&a.intersection(&b)), &va & &vb);
+            prop_assert_eq!(values(&a.difference(&b)), &va - &vb);
+        }
+
+        #[test]
+        fn prop_set_is_normalized(a in set_strategy(), b in set_strategy()) {
+            for set in [a.union(&b), a.intersection(&b), a.difference(&b)] {
+                prop_assert!(set.ranges().iter().all(|range| range.start < range.end));
+                prop_assert!(set.ranges().windows(2).all(|pair| pair[0].end < pair[1].start));
+            }
+        }
+
+        #[test]
+        fn prop_apply_range_matches_apply(map in map_strategy(), range in range_strategy()) {
+            let expected = range.clone().map(|value| map.apply(value)).collect::<BTreeSet<_>>();
+            prop_assert_eq!(values(&map.apply_range(range)), expected);
+        }
+
+        #[test]
+        fn prop_compose_matches_apply(first in map_strategy(), second in map_strategy()) {
+            let composed = first.compose(&second);
+            for value in 0..DOMAIN * 2 {
+                prop_assert_eq!(composed.apply(value), second.apply(first.apply(value)));
+            }
+        }
+
+        #[test]
+        fn prop_invert_roundtrip(map in permutation_strategy()) {
+            let inverse = map.invert().unwrap();
+            for value in 0..DOMAIN * 2 {
+                prop_assert_eq!(inverse.apply(map.apply(value)), value);
+            }
+        }
+
+        #[test]
+        fn prop_invert_only_bijections(map in map_strategy()) {
+            if let Some(inverse) = map.invert() {
+                for value in 0..DOMAIN * 2 {
+                    prop_assert_eq!(inverse.apply(map.apply(value)), value);
+                }
+            }
+        }
+    }
+}
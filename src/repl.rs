@@ -0,0 +1,138 @@
+use std::io::{self, BufRead, Write};
+
+type Handler<'a, M> = Box<dyn Fn(&M, &[&str]) -> anyhow::Result<String> + 'a>;
+
+struct Command<'a, M> {
+    name: &'static str,
+    usage: &'static str,
+    handler: Handler<'a, M>,
+}
+
+/// Read-eval-print loop running the commands a day registers against its parsed model.
+pub struct Repl<'a, M> {
+    prompt: String,
+    commands: Vec<Command<'a, M>>,
+}
+
+impl<'a, M> Repl<'a, M> {
+    #[must_use]
+    pub fn new(prompt: &str) -> Self {
+        Self {
+            prompt: format!("{prompt}> "),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Registers a command, the handler gets the model and the words after the command name.
+    #[must_use]
+    pub fn command(
+        mut self,
+        name: &'static str,
+        usage: &'static str,
+        handler: impl Fn(&M, &[&str]) -> anyhow::Result<String> + 'a,
+    ) -> Self {
+        self.commands.push(Command {
+            name,
+            usage,
+            handler: Box::new(handler),
+        });
+        self
+    }
+
+    fn help(&self) -> String {
+        let mut help = self
+            .commands
+            .iter()
+            .map(|command| command.usage)
+            .collect::<Vec<_>>();
+        help.extend(["help", "quit"]);
+        help.join("\n")
+    }
+
+    /// Evaluates a line of input, `None` if the REPL should quit.
+    pub fn eval(&self, model: &M, line: &str) -> Option<anyhow::Result<String>> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let Some((name, args)) = words.split_first() else {
+            return Some(Ok(String::new()));
+        };
+
+        match *name {
+            "quit" | "exit" => None,
+            "help" => Some(Ok(self.help())),
+            _ => Some(
+                self.commands
+                    .iter()
+                    .find(|command| command.name == *name)
+                    .ok_or(anyhow::anyhow!("Unknown Command {name}! Try help."))
+                    .and_then(|command| (command.handler)(model, args)),
+            ),
+        }
+    }
+
+    /// Runs the loop until the input ends or `quit` is entered.
+    ///
+    /// # Errors
+    ///
+    /// On I/O errors.
+    pub fn run(&self, model: &M, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        write!(output, "{}", self.prompt)?;
+        output.flush()?;
+        for line in input.lines() {
+            match self.eval(model, &line?) {
+                None => break,
+                Some(Ok(result)) if result.is_empty() => {}
+                Some(Ok(result)) => writeln!(output, "{result}")?,
+                Some(Err(err)) => writeln!(output, "error: {err}")?,
+            }
+            write!(output, "{}", self.prompt)?;
+            output.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the argument of a command, naming it in the error.
+///
+/// # Errors
+///
+/// If the argument is missing or doesn't parse.
+pub fn arg<T: std::str::FromStr>(args: &[&str], index: usize, name: &str) -> anyhow::Result<T> {
+    let arg = args
+        .get(index)
+        .ok_or(anyhow::anyhow!("Missing Argument {name}!"))?;
+    str::parse::<T>(arg).map_err(|_| anyhow::anyhow!("Invalid Argument {name}: {arg}!"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{arg, Repl};
+
+    #[test]
+    fn test_repl() {
+        let repl = Repl::new("test").command("add", "add <a> <b>", |offset: &i32, args| {
+            Ok((offset + arg::<i32>(args, 0, "a")? + arg::<i32>(args, 1, "b")?).to_string())
+        });
+
+        assert_eq!(repl.eval(&10, "add 1 2").unwrap().unwrap(), "13");
+        assert_eq!(repl.eval(&10, "  ").unwrap().unwrap(), "");
+        assert_eq!(
+            repl.eval(&10, "help").unwrap().unwrap(),
+            "add <a> <b>\nhelp\nquit"
+        );
+        assert!(repl.eval(&10, "add 1").unwrap().is_err());
+        assert!(repl.eval(&10, "sub 1 2").unwrap().is_err());
+        assert!(repl.eval(&10, "quit").is_none());
+
+        let mut output = Vec::new();
+        repl.run(
+            &0,
+            "add 1 2\nadd x 2\nquit\nadd 3 4\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "test> 3\ntest> error: Invalid Argument a: x!\ntest> "
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use std::{fmt::Write, path::Path, str::FromStr, time::Duration};
+
+use crate::{
+    answers::{AnswerStore, Verdict},
+    puzzle::{Puzzle, DAYS},
+    runner,
+};
+
+/// Median runtime and peak memory of repeated runs of a solution and the verdict on its
+/// answer, the answer itself is left out so reports can be published.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub verdict: Verdict,
+    pub median: Duration,
+    pub peak_memory: Option<u64>,
+}
+
+/// Row of the report, the measurement fails if the solution fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub puzzle: Puzzle,
+    pub measurement: Result<Measurement, String>,
+    pub lines_of_code: Option<usize>,
+}
+
+/// Order of the report rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortKey {
+    #[default]
+    Puzzle,
+    Runtime,
+    Memory,
+    Lines,
+}
+
+impl FromStr for SortKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "puzzle" => Ok(Self::Puzzle),
+            "runtime" => Ok(Self::Runtime),
+            "memory" => Ok(Self::Memory),
+            "lines" => Ok(Self::Lines),
+            _ => Err(format!(
+                "Invalid Sort Key {s}! (puzzle, runtime, memory, lines)"
+            )),
+        }
+    }
+}
+
+/// Lines of code of a solution, without blank lines, comments and the test module.
+#[must_use]
+pub fn lines_of_code(source: &str) -> usize {
+    let lines = source.lines().map(str::trim).collect::<Vec<_>>();
+    let end = lines
+        .windows(2)
+        .position(|pair| pair[0] == "#[cfg(test)]" && pair[1].starts_with("mod "))
+        .unwrap_or(lines.len());
+    lines[..end]
+        .iter()
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .count()
+}
+
+/// Median of the durations, the mean of the middle two for an even count.
+#[must_use]
+pub fn median(durations: &[Duration]) -> Option<Duration> {
+    let mut durations = durations.to_vec();
+    durations.sort_unstable();
+    let middle = durations.len() / 2;
+    match durations.len() {
+        0 => None,
+        len if len % 2 == 0 => Some((durations[middle - 1] + durations[middle]) / 2),
+        _ => Some(durations[middle]),
+    }
+}
+
+/// Runs the solution on the input the given number of times and verifies its answer.
+///
+/// # Errors
+///
+/// If a run fails or the answers of the runs differ.
+pub fn measure(
+    bin_dir: &Path,
+    store: &AnswerStore,
+    puzzle: Puzzle,
+    input: &str,
+    runs: usize,
+) -> anyhow::Result<Measurement> {
+    let mut answer = None;
+    let mut durations = Vec::new();
+    let mut peak_memory = None;
+
+    for _ in 0..runs.max(1) {
+        let run = runner::run_solution_timed(bin_dir, puzzle, input, None)?;
+        if answer.as_ref().is_some_and(|answer| *answer != run.answer) {
+            return Err(anyhow::anyhow!("Answers differ between runs!"));
+        }
+        durations.push(run.elapsed);
+        peak_memory = peak_memory.max(run.peak_memory);
+        answer = Some(run.answer);
+    }
+
+    let answer = answer.ok_or(anyhow::anyhow!("No Runs!"))?;
+    Ok(Measurement {
+        verdict: store.verify(puzzle, &answer),
+        median: median(&durations).unwrap_or_default(),
+        peak_memory,
+    })
+}
+
+fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1e6;
+    if micros < 1e3 {
+        format!("{micros:.0} µs")
+    } else if micros < 1e6 {
+        format!("{:.1} ms", micros / 1e3)
+    } else {
+        format!("{:.2} s", micros / 1e6)
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: u64) -> String {
+    let kib = bytes as f64 / 1024.0;
+    if kib < 1024.0 {
+        format!("{kib:.0} KiB")
+    } else {
+        format!("{:.1} MiB", kib / 1024.0)
+    }
+}
+
+/// Report of the solutions, rendered as Markdown or HTML.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub entries: Vec<Entry>,
+}
+
+impl Report {
+    const HEADER: [&'static str; 5] = [
+        "Puzzle",
+        "Status",
+        "Median Runtime",
+        "Peak Memory",
+        "Lines of Code",
+    ];
+
+    #[must_use]
+    pub fn new(mut entries: Vec<Entry>, sort: SortKey) -> Self {
+        let runtime = |entry: &Entry| entry.measurement.as_ref().ok().map(|m| m.median);
+        let memory = |entry: &Entry| entry.measurement.as_ref().ok().and_then(|m| m.peak_memory);
+        entries.sort_by_key(|entry| entry.puzzle);
+        match sort {
+            SortKey::Puzzle => {}
+            SortKey::Runtime => entries.sort_by_key(|entry| std::cmp::Reverse(runtime(entry))),
+            SortKey::Memory => entries.sort_by_key(|entry| std::cmp::Reverse(memory(entry))),
+            SortKey::Lines => {
+                entries.sort_by_key(|entry| std::cmp::Reverse(entry.lines_of_code));
+            }
+        }
+        Self { entries }
+    }
+
+    /// Implemented and verified parts of each year, out of all the parts of the year.
+    fn progress(&self) -> Vec<String> {
+        let mut years = self
+            .entries
+            .iter()
+            .map(|entry| entry.puzzle.year)
+            .collect::<Vec<_>>();
+        years.sort_unstable();
+        years.dedup();
+
+        years
+            .into_iter()
+            .map(|year| {
+                let entries = self
+                    .entries
+                    .iter()
+                    .filter(|entry| entry.puzzle.year == year);
+                let verified = entries
+                    .clone()
+                    .filter(|entry| {
+                        entry
+                            .measurement
+                            .as_ref()
+                            .is_ok_and(|m| m.verdict == Verdict::Correct)
+                    })
+                    .count();
+                format!(
+                    "{year}: {} of {} parts implemented, {verified} verified",
+                    entries.count(),
+                    DAYS.len() * 2
+                )
+            })
+            .collect()
+    }
+
+    fn rows(&self) -> Vec<[String; 5]> {
+        let mut rows = Vec::new();
+        for entry in &self.entries {
+            let lines = entry
+                .lines_of_code
+                .map_or(String::new(), |lines| lines.to_string());
+            let row = match &entry.measurement {
+                Ok(m) => [
+                    entry.puzzle.to_string(),
+                    match &m.verdict {
+                        Verdict::Correct => "correct".to_string(),
+                        Verdict::Wrong => "wrong".to_string(),
+                        Verdict::Unknown => "unverified".to_string(),
+                    },
+                    format_duration(m.median),
+                    m.peak_memory.map_or(String::new(), format_bytes),
+                    lines,
+                ],
+                Err(err) => [
+                    entry.puzzle.to_string(),
+                    format!("failed: {err}"),
+                    String::new(),
+                    String::new(),
+                    lines,
+                ],
+            };
+            rows.push(row);
+        }
+
+        let measurements = self
+            .entries
+            .iter()
+            .filter_map(|entry| entry.measurement.as_ref().ok());
+        rows.push([
+            "Total".to_string(),
+            String::new(),
+            format_duration(measurements.clone().map(|m| m.median).sum()),
+            measurements
+                .filter_map(|m| m.peak_memory)
+                .max()
+                .map_or(String::new(), format_bytes),
+            self.entries
+                .iter()
+                .filter_map(|entry| entry.lines_of_code)
+                .sum::<usize>()
+                .to_string(),
+        ]);
+        rows
+    }
+
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::from("# Advent of Code\n\n");
+        for line in self.progress() {
+            let _ = writeln!(markdown, "- {line}");
+        }
+        let _ = writeln!(markdown, "\n| {} |", Self::HEADER.join(" | "));
+        let _ = writeln!(markdown, "|---|---|--:|--:|--:|");
+        for row in self.rows() {
+            let cells = row.map(|cell| cell.replace('|', "\\|"));
+            let _ = writeln!(markdown, "| {} |", cells.join(" | "));
+        }
+        markdown
+    }
+
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let escape = |text: &str| {
+            text.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        };
+
+        let mut html = String::from(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Advent of Code</title>\n</head>\n<body>\n<h1>Advent of Code</h1>\n<ul>\n",
+        );
+        for line in self.progress() {
+            let _ = writeln!(html, "<li>{}</li>", escape(&line));
+        }
+        let _ = writeln!(html, "</ul>\n<table>");
+        let header = Self::HEADER.map(|cell| format!("<th>{cell}</th>"));
+        let _ = writeln!(html, "<tr>{}</tr>", header.concat());
+        for row in self.rows() {
+            let cells = row.map(|cell| format!("<td>{}</td>", escape(&cell)));
+            let _ = writeln!(html, "<tr>{}</tr>", cells.concat());
+        }
+        html.push_str("</table>\n</body>\n</html>\n");
+        html
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{lines_of_code, median, Entry, Measurement, Report, SortKey};
+    use crate::{answers::Verdict, puzzle::Puzzle};
+
+    #[test]
+    fn test_lines_of_code() {
+        let source = "use std::fs;\n\n// comment\nfn main() {\n    /// doc\n}\n\n\
+                      #[cfg(test)]\nmod tests {\n    fn test() {}\n}\n";
+        assert_eq!(lines_of_code(source), 3);
+        assert_eq!(lines_of_code("#[cfg(test)]\nfn helper() {}"), 2);
+    }
+
+    #[test]
+    fn test_median() {
+        let ms = Duration::from_millis;
+        assert_eq!(median(&[]), None);
+        assert_eq!(median(&[ms(5), ms(1), ms(3)]), Some(ms(3)));
+        assert_eq!(median(&[ms(4), ms(1), ms(2), ms(100)]), Some(ms(3)));
+    }
+
+    fn entries() -> Vec<Entry> {
+        vec![
+            Entry {
+                puzzle: Puzzle::new(2023, 2, 1),
+                measurement: Ok(Measurement {
+                    verdict: Verdict::Unknown,
+                    median: Duration::from_micros(2500),
+                    peak_memory: Some(3 * 1024 * 1024),
+                }),
+                lines_of_code: Some(40),
+            },
+            Entry {
+                puzzle: Puzzle::new(2023, 1, 1),
+                measurement: Ok(Measurement {
+                    verdict: Verdict::Correct,
+                    median: Duration::from_micros(800),
+                    peak_memory: Some(2048 * 1024),
+                }),
+                lines_of_code: Some(20),
+            },
+            Entry {
+                puzzle: Puzzle::new(2023, 1, 2),
+                measurement: Err("exit status: 101".to_string()),
+                lines_of_code: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let report = Report::new(entries(), SortKey::Puzzle);
+        assert_eq!(
+            report.to_markdown(),
+            "# Advent of Code
+
+- 2023: 3 of 50 parts implemented, 1 verified
+
+| Puzzle | Status | Median Runtime | Peak Memory | Lines of Code |
+|---|---|--:|--:|--:|
+| 2023 Day 1 Part 1 | correct | 800 µs | 2.0 MiB | 20 |
+| 2023 Day 1 Part 2 | failed: exit status: 101 |  |  |  |
+| 2023 Day 2 Part 1 | unverified | 2.5 ms | 3.0 MiB | 40 |
+| Total |  | 3.3 ms | 3.0 MiB | 60 |
+"
+        );
+    }
+
+    #[test]
+    fn test_sort_and_html() {
+        let report = Report::new(entries(), SortKey::Runtime);
+        let puzzles = report
+            .entries
+            .iter()
+            .map(|entry| entry.puzzle.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            puzzles,
+            [
+                "2023 Day 2 Part 1",
+                "2023 Day 1 Part 1",
+                "2023 Day 1 Part 2"
+            ]
+        );
+
+        let html = report.to_html();
+        assert!(html.contains("<tr><td>2023 Day 1 Part 1</td><td>correct</td><td>800 µs</td>"));
+        assert!(!html.contains("142"));
+        assert!(html.contains("<tr><td>Total</td>"));
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    fmt,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process::{Child, Command, ExitStatus, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::puzzle::Puzzle;
+
+/// Directory of the running executable, where cargo puts the solution binaries as well.
+///
+/// # Errors
+///
+/// If the path of the executable can't be determined.
+pub fn bin_dir() -> anyhow::Result<PathBuf> {
+    let exe = std::env::current_exe()?;
+    exe.parent()
+        .map(Path::to_path_buf)
+        .ok_or(anyhow::anyhow!("Invalid Executable Path!"))
+}
+
+/// Path of the binary solving the puzzle in the directory.
+#[must_use]
+pub fn bin_path(bin_dir: &Path, puzzle: Puzzle) -> PathBuf {
+    bin_dir.join(format!(
+        "{}{}",
+        puzzle.bin_name(),
+        std::env::consts::EXE_SUFFIX
+    ))
+}
+
+/// Puzzles of all solution binaries in the directory, sorted.
+///
+/// # Errors
+///
+/// If the directory can't be read.
+pub fn solutions(bin_dir: &Path) -> anyhow::Result<Vec<Puzzle>> {
+    let mut solutions = Vec::new();
+    for entry in std::fs::read_dir(bin_dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+        let Some(solution) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(std::env::consts::EXE_SUFFIX))
+            .and_then(Puzzle::from_bin_name)
+        else {
+            continue;
+        };
+        solutions.push(solution);
+    }
+    solutions.sort_unstable();
+    Ok(solutions)
+}
+
+/// The answer printed by a solution, the last word of its last line of output.
+#[must_use]
+pub fn answer_from_output(output: &str) -> Option<&str> {
+    output
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .and_then(|line| line.split_whitespace().last())
+}
+
+/// Answer of a solution run, how long it took and how much memory it used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub answer: String,
+    pub elapsed: Duration,
+    /// Peak resident memory in bytes, where the platform reports it
+    pub peak_memory: Option<u64>,
+}
+
+/// Error of a solution run killed after exceeding its time budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout(pub Duration);
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Time budget of {:?} exceeded!", self.0)
+    }
+}
+
+impl std::error::Error for Timeout {}
+
+/// Runs the solution binary from the directory with the input piped to stdin and
+/// returns its answer.
+///
+/// # Errors
+///
+/// If the binary can't be started, fails or doesn't print an answer.
+pub fn run_solution(bin_dir: &Path, puzzle: Puzzle, input: &str) -> anyhow::Result<String> {
+    run_solution_timed(bin_dir, puzzle, input, None).map(|run| run.answer)
+}
+
+/// Like [`run_solution`] but measures the run and kills the solution when it takes
+/// longer than the time budget.
+///
+/// # Errors
+///
+/// If the binary can't be started, fails or doesn't print an answer, and [`Timeout`]
+/// if it exceeds the time budget.
+pub fn run_solution_timed(
+    bin_dir: &Path,
+    puzzle: Puzzle,
+    input: &str,
+    budget: Option<Duration>,
+) -> anyhow::Result<Run> {
+    run_solution_with_args(bin_dir, puzzle, input, &[], budget)
+}
+
+/// Like [`run_solution_timed`] but passes the arguments, like options of the solution,
+/// to the binary.
+///
+/// # Errors
+///
+/// See [`run_solution_timed`].
+pub fn run_solution_with_args(
+    bin_dir: &Path,
+    puzzle: Puzzle,
+    input: &str,
+    args: &[String],
+    budget: Option<Duration>,
+) -> anyhow::Result<Run> {
+    let name = puzzle.bin_name();
+    let start = Instant::now();
+    let mut child = Command::new(bin_path(bin_dir, puzzle))
+        .arg("-")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| anyhow::anyhow!("Error starting {name}: {err}"))?;
+
+    // the pipes are served from threads, a solution blocked on a full pipe never finishes
+    let mut stdin = child
+        .stdin
+        .take()
+        .ok_or(anyhow::anyhow!("Missing stdin of {name}!"))?;
+    let input = input.to_string();
+    // a solution failing early closes stdin, its exit status tells what went wrong
+    thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+
+    let (status, peak_memory) = loop {
+        if let Some(exited) = try_wait(&mut child)? {
+            break exited;
+        }
+        if let Some(budget) = budget.filter(|budget| start.elapsed() >= *budget) {
+            child.kill()?;
+            child.wait()?;
+            return Err(Timeout(budget).into());
+        }
+        thread::sleep(Duration::from_millis(1));
+    };
+    let elapsed = start.elapsed();
+
+    let stdout = stdout.join().unwrap_or_default();
+    if !status.success() {
+        let stderr = stderr.join().unwrap_or_default();
+        return Err(anyhow::anyhow!(
+            "{name} failed ({status}): {}",
+            String::from_utf8_lossy(&stderr).trim()
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&stdout);
+    let answer = answer_from_output(&stdout)
+        .map(str::to_string)
+        .ok_or(anyhow::anyhow!("{name} printed no answer!"))?;
+    Ok(Run {
+        answer,
+        elapsed,
+        peak_memory,
+    })
+}
+
+/// Like [`Child::try_wait`] but also returns the peak resident memory in bytes of the
+/// exited child.
+#[cfg(unix)]
+fn try_wait(child: &mut Child) -> io::Result<Option<(ExitStatus, Option<u64>)>> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let pid = libc::pid_t::try_from(child.id()).map_err(io::Error::other)?;
+    let mut status = 0;
+    // SAFETY: rusage is a plain C struct, all zeros is a valid value
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    // SAFETY: both pointers are valid for the call, the child is only ever reaped here
+    match unsafe { libc::wait4(pid, &raw mut status, libc::WNOHANG, &raw mut usage) } {
+        -1 => Err(io::Error::last_os_error()),
+        0 => Ok(None),
+        _ => {
+            // the maximum resident set size is in kilobytes, except on macos
+            let unit = if cfg!(target_os = "macos") { 1 } else { 1024 };
+            let peak_memory = u64::try_from(usage.ru_maxrss).ok().map(|size| size * unit);
+            Ok(Some((ExitStatus::from_raw(status), peak_memory)))
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn try_wait(child: &mut Child) -> io::Result<Option<(ExitStatus, Option<u64>)>> {
+    Ok(child.try_wait()?.map(|status| (status, None)))
+}
+
+fn read_pipe(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::answer_from_output;
+
+    #[test]
+    fn test_answer_from_output() {
+        assert_eq!(answer_from_output("Sum: 142\n"), Some("142"));
+        assert_eq!(
+            answer_from_output("1abc2 -> 12 => 12\nSum: 142\n\n"),
+            Some("142")
+        );
+        assert_eq!(answer_from_output("Smallest Range Start: 46"), Some("46"));
+        assert_eq!(answer_from_output("\n"), None);
+    }
+}
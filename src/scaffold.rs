@@ -0,0 +1,319 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::puzzle::{validate_day, Puzzle, DAYS};
+
+/// Library module parsing and solving a part of a new day, `no_std` like the other solvers.
+const SOLVER_TEMPLATE: &str = r#"use alloc::vec::Vec;
+
+pub fn parse_contents(contents: &str) -> Vec<&str> {
+    contents.trim().lines().map(str::trim).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_contents;
+
+    const EXAMPLE_INPUT: &str = "
+    ";
+
+    #[test]
+    fn test_parse_contents() {
+        assert!(parse_contents(EXAMPLE_INPUT).is_empty());
+    }
+}
+"#;
+
+/// Binary of a part of a new day calling its solver, `{year}`, `{day}` and `{part}` are
+/// replaced.
+const BIN_TEMPLATE: &str = r#"#![warn(clippy::pedantic)]
+use adventofcode::{input::read_input, year{year}::day{day}::part{part}::parse_contents};
+
+fn main() {
+    let contents = read_input({year}, {day}).expect("Invalid Input!");
+    let lines = parse_contents(&contents);
+    println!("Solution: {}", lines.len());
+}
+"#;
+
+/// Source path of the binary of the puzzle, relative to the crate root.
+#[must_use]
+pub fn source_path(puzzle: Puzzle) -> PathBuf {
+    Path::new("src")
+        .join(format!("year{}", puzzle.year))
+        .join(format!("day{}_part{}.rs", puzzle.day, puzzle.part))
+}
+
+/// Source path of the library module parsing and solving the puzzle, relative to the crate
+/// root.
+#[must_use]
+pub fn solver_path(puzzle: Puzzle) -> PathBuf {
+    Path::new("src")
+        .join(format!("year{}", puzzle.year))
+        .join(format!("day{}", puzzle.day))
+        .join(format!("part{}.rs", puzzle.part))
+}
+
+/// Source path of the library module of the year, relative to the crate root.
+fn year_module_path(year: u16) -> PathBuf {
+    Path::new("src").join(format!("year{year}.rs"))
+}
+
+/// Adds the module of the year to the library, before the first module sorting after it.
+fn add_year_module(lib: &str, year: u16) -> String {
+    let module = format!("pub mod year{year};");
+    let mut lines = lib.lines().collect::<Vec<_>>();
+    let mut index = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod ") && *line > module.as_str())
+        .unwrap_or(lines.len());
+    // keep the attributes with the module following them
+    while index > 0 && lines[index - 1].starts_with("#[") {
+        index -= 1;
+    }
+    lines.insert(index, &module);
+    lines.join("\n") + "\n"
+}
+
+/// Adds the module of the day with both parts to the module of its year, before the next day.
+fn add_day_module(year_module: &str, day: u8) -> String {
+    let module = format!("pub mod day{day} {{\n    pub mod part1;\n    pub mod part2;\n}}\n");
+    let next = DAYS
+        .filter(|next| *next > day)
+        .find_map(|next| year_module.find(&format!("pub mod day{next} {{")));
+    match next {
+        Some(index) => format!(
+            "{}{module}\n{}",
+            &year_module[..index],
+            &year_module[index..]
+        ),
+        None => format!("{}\n\n{module}", year_module.trim_end_matches('\n')),
+    }
+}
+
+/// Adds the `[[bin]]` target of the puzzle to the manifest, after the last solution binary.
+fn add_bin_target(manifest: &str, puzzle: Puzzle) -> String {
+    let target = format!(
+        "[[bin]]\nname = \"{}\"\npath = \"{}\"\nrequired-features = [\"std\"]\n",
+        puzzle.bin_name(),
+        source_path(puzzle).display()
+    );
+
+    let insert_at = manifest
+        .match_indices("[[bin]]\nname = \"year")
+        .last()
+        .map(|(index, _)| {
+            manifest[index..]
+                .find("\n\n")
+                .map_or(manifest.len(), |end| index + end + 2)
+        });
+    match insert_at {
+        Some(index) => format!("{}{target}\n{}", &manifest[..index], &manifest[index..]),
+        None => format!(
+            "{}\n{target}",
+            manifest.trim_end_matches('\n').to_string() + "\n"
+        ),
+    }
+}
+
+/// Creates the solvers and binaries of both parts of a new day from the templates, registers
+/// the solvers in the module of the year and the binaries in the manifest of the crate at the
+/// root, returns the created files.
+///
+/// # Errors
+///
+/// If the day is invalid, a solution of the day exists already or on I/O errors.
+pub fn scaffold_day(root: &Path, year: u16, day: u8) -> anyhow::Result<Vec<PathBuf>> {
+    validate_day(year, day)?;
+
+    let puzzles = [Puzzle::new(year, day, 1), Puzzle::new(year, day, 2)];
+    let manifest_path = root.join("Cargo.toml");
+    let mut manifest = fs::read_to_string(&manifest_path)?;
+    let year_path = root.join(year_module_path(year));
+    let year_module = if year_path.exists() {
+        Some(fs::read_to_string(&year_path)?)
+    } else {
+        None
+    };
+
+    let mut created = Vec::new();
+    for puzzle in puzzles {
+        let paths = [
+            root.join(solver_path(puzzle)),
+            root.join(source_path(puzzle)),
+        ];
+        if paths.iter().any(|path| path.exists())
+            || manifest.contains(&format!("name = \"{}\"", puzzle.bin_name()))
+        {
+            return Err(anyhow::anyhow!("Solution of {puzzle} exists already!"));
+        }
+        created.extend(paths);
+    }
+    if year_module
+        .as_ref()
+        .is_some_and(|module| module.contains(&format!("pub mod day{day} {{")))
+    {
+        return Err(anyhow::anyhow!(
+            "Module of {year} Day {day} exists already!"
+        ));
+    }
+
+    for puzzle in puzzles {
+        let bin = BIN_TEMPLATE
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+            .replace("{part}", &puzzle.part.to_string());
+        for (path, source) in [
+            (solver_path(puzzle), SOLVER_TEMPLATE),
+            (source_path(puzzle), bin.as_str()),
+        ] {
+            let path = root.join(path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, source)?;
+        }
+        manifest = add_bin_target(&manifest, puzzle);
+    }
+    fs::write(manifest_path, manifest)?;
+
+    // the first day of a year also creates the module of the year
+    let year_module = if let Some(year_module) = year_module {
+        year_module
+    } else {
+        let lib_path = root.join("src").join("lib.rs");
+        let lib = fs::read_to_string(&lib_path)?;
+        fs::write(lib_path, add_year_module(&lib, year))?;
+        created.push(year_path.clone());
+        format!(
+            "//! Parsing and solving of the {year} puzzles, the binaries of the days only read the \
+             input\n//! and print the answers.\n"
+        )
+    };
+    fs::write(&year_path, add_day_module(&year_module, day))?;
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{add_bin_target, add_day_module, add_year_module, scaffold_day};
+    use crate::puzzle::Puzzle;
+
+    const MANIFEST: &str = "[package]
+name = \"adventofcode\"
+
+[[bin]]
+name = \"year2023_day1_part1\"
+path = \"src/year2023/day1_part1.rs\"
+required-features = [\"std\"]
+
+[[bin]]
+name = \"aoc\"
+path = \"src/aoc.rs\"
+required-features = [\"std\"]
+";
+
+    #[test]
+    fn test_add_bin_target() {
+        let manifest = add_bin_target(MANIFEST, Puzzle::new(2015, 3, 2));
+        assert_eq!(
+            manifest,
+            MANIFEST.replace(
+                "[[bin]]\nname = \"aoc\"",
+                "[[bin]]\nname = \"year2015_day3_part2\"\npath = \"src/year2015/day3_part2.rs\"\n\
+                 required-features = [\"std\"]\n\n\
+                 [[bin]]\nname = \"aoc\""
+            )
+        );
+
+        let manifest = add_bin_target("[package]\nname = \"empty\"\n", Puzzle::new(2015, 3, 2));
+        assert_eq!(
+            manifest,
+            "[package]\nname = \"empty\"\n\n\
+             [[bin]]\nname = \"year2015_day3_part2\"\npath = \"src/year2015/day3_part2.rs\"\n\
+             required-features = [\"std\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_modules() {
+        let day = "pub mod day{} {\n    pub mod part1;\n    pub mod part2;\n}\n";
+        let [day3, day5, day6] = [3, 5, 6].map(|n| day.replace("{}", &n.to_string()));
+        let header = "//! Header.\n";
+
+        assert_eq!(add_day_module(header, 5), format!("{header}\n{day5}"));
+        assert_eq!(
+            add_day_module(&format!("{header}\n{day3}\n{day6}"), 5),
+            format!("{header}\n{day3}\n{day5}\n{day6}")
+        );
+
+        let lib = "pub mod answer;\n#[cfg(feature = \"std\")]\npub mod watch;\npub mod year2023;\n";
+        assert_eq!(
+            add_year_module(lib, 2015),
+            lib.replace("pub mod year2023;", "pub mod year2015;\npub mod year2023;")
+        );
+        assert_eq!(
+            add_year_module(lib, 2024),
+            format!("{lib}pub mod year2024;\n")
+        );
+        let lib = "pub mod answer;\n#[cfg(feature = \"std\")]\npub mod year2023;\n";
+        assert_eq!(
+            add_year_module(lib, 2015),
+            lib.replace("#[cfg", "pub mod year2015;\n#[cfg")
+        );
+    }
+
+    #[test]
+    fn test_scaffold_day() {
+        let dir = std::env::temp_dir().join(format!("scaffold-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("Cargo.toml"), MANIFEST).unwrap();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "pub mod year2023;\n").unwrap();
+
+        let created = scaffold_day(&dir, 2015, 1).unwrap();
+        assert_eq!(
+            created,
+            [
+                dir.join("src/year2015/day1/part1.rs"),
+                dir.join("src/year2015/day1_part1.rs"),
+                dir.join("src/year2015/day1/part2.rs"),
+                dir.join("src/year2015/day1_part2.rs"),
+                dir.join("src/year2015.rs")
+            ]
+        );
+        assert!(fs::read_to_string(&created[2])
+            .unwrap()
+            .contains("pub fn parse_contents"));
+        let bin = fs::read_to_string(&created[3]).unwrap();
+        assert!(bin.contains("year2015::day1::part2::parse_contents"));
+        assert!(bin.contains("read_input(2015, 1)"));
+        assert_eq!(
+            fs::read_to_string(dir.join("src/lib.rs")).unwrap(),
+            "pub mod year2015;\npub mod year2023;\n"
+        );
+        assert!(fs::read_to_string(&created[4])
+            .unwrap()
+            .ends_with("\n\npub mod day1 {\n    pub mod part1;\n    pub mod part2;\n}\n"));
+        let manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"year2015_day1_part1\""));
+        assert!(manifest.contains("name = \"year2015_day1_part2\""));
+
+        assert!(scaffold_day(&dir, 2015, 1).is_err());
+        let created = scaffold_day(&dir, 2015, 2).unwrap();
+        assert_eq!(created.len(), 4);
+        assert!(fs::read_to_string(dir.join("src/year2015.rs"))
+            .unwrap()
+            .contains("pub mod day2 {"));
+        assert!(scaffold_day(&dir, 2014, 1).is_err());
+        assert!(scaffold_day(&dir, 2015, 26).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
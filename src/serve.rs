@@ -0,0 +1,224 @@
+use std::{
+    fmt,
+    io::Read,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Server};
+
+use crate::{
+    puzzle::{Puzzle, DEFAULT_YEAR},
+    runner::{self, Timeout},
+};
+
+/// Limits and location of the solutions served.
+#[derive(Debug, Clone)]
+pub struct ServeConfig {
+    pub bin_dir: PathBuf,
+    /// Solutions running longer are killed and answered with `504`
+    pub time_budget: Duration,
+    /// Larger inputs are rejected with `413`
+    pub max_input_size: usize,
+    /// Requests beyond this many in flight are rejected with `503`
+    pub max_concurrent: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct Listing {
+    year: u16,
+    day: u8,
+    parts: Vec<u8>,
+}
+
+#[derive(Debug, Serialize)]
+struct Solved {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: String,
+    elapsed_ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct Failure {
+    error: String,
+}
+
+/// Status code and JSON body of a response.
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: &impl Serialize) -> Self {
+        let body = serde_json::to_string(body).unwrap_or_else(|err| format!("{err}"));
+        Self { status, body }
+    }
+
+    fn error(status: u16, error: &impl fmt::Display) -> Self {
+        Self::json(
+            status,
+            &Failure {
+                error: error.to_string(),
+            },
+        )
+    }
+}
+
+/// Releases its slot of the requests in flight when the request is done, also on panic.
+struct InFlight(Arc<AtomicUsize>);
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Answers the requests of the server until it is shut down, each request on its own thread
+/// as long as fewer than [`ServeConfig::max_concurrent`] are in flight.
+pub fn serve(server: &Server, config: ServeConfig) {
+    let config = Arc::new(config);
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    for mut request in server.incoming_requests() {
+        // only this loop takes slots, so the check can't race with another increment
+        if in_flight.load(Ordering::SeqCst) >= config.max_concurrent {
+            respond(request, Response::error(503, &"Server Busy!"));
+            continue;
+        }
+        in_flight.fetch_add(1, Ordering::SeqCst);
+        let slot = InFlight(Arc::clone(&in_flight));
+        let config = Arc::clone(&config);
+        thread::spawn(move || {
+            let _slot = slot;
+            let response = handle(&config, &mut request);
+            respond(request, response);
+        });
+    }
+}
+
+fn respond(request: Request, response: Response) {
+    let mut http_response =
+        tiny_http::Response::from_string(response.body).with_status_code(response.status);
+    if let Ok(content_type) = Header::from_bytes("Content-Type", "application/json") {
+        http_response.add_header(content_type);
+    }
+    let _ = request.respond(http_response);
+}
+
+/// Routes the request:
+///
+/// * `GET /days` lists the years, days and parts with a solution
+/// * `POST /solve/{year}/{day}/{part}` solves the puzzle input in the body
+/// * `POST /solve/{day}/{part}` is the same for the default year
+pub fn handle(config: &ServeConfig, request: &mut Request) -> Response {
+    let url = request
+        .url()
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let segments = url.trim_matches('/').split('/').collect::<Vec<&str>>();
+
+    match (request.method(), segments.as_slice()) {
+        (Method::Get, ["days"]) => days(config),
+        (Method::Post, ["solve", puzzle @ ..]) if matches!(puzzle.len(), 2 | 3) => {
+            let Some(puzzle) = parse_puzzle(puzzle) else {
+                return Response::error(400, &"Invalid Year, Day or Part!");
+            };
+            match read_body(request, config.max_input_size) {
+                Ok(input) => solve(config, puzzle, &input),
+                Err(response) => response,
+            }
+        }
+        (_, ["days"] | ["solve", _, _] | ["solve", _, _, _]) => {
+            Response::error(405, &"Method Not Allowed!")
+        }
+        _ => Response::error(404, &"Not Found!"),
+    }
+}
+
+/// Parses the `[year, ]day, part` segments of the path.
+fn parse_puzzle(segments: &[&str]) -> Option<Puzzle> {
+    let (year, day, part) = match segments {
+        [day, part] => (DEFAULT_YEAR, day, part),
+        [year, day, part] => (year.parse().ok()?, day, part),
+        _ => return None,
+    };
+    Some(Puzzle::new(year, day.parse().ok()?, part.parse().ok()?))
+}
+
+fn days(config: &ServeConfig) -> Response {
+    let solutions = match runner::solutions(&config.bin_dir) {
+        Ok(solutions) => solutions,
+        Err(err) => return Response::error(500, &err),
+    };
+
+    let mut days: Vec<Listing> = Vec::new();
+    for puzzle in solutions {
+        match days.last_mut() {
+            Some(last) if (last.year, last.day) == (puzzle.year, puzzle.day) => {
+                last.parts.push(puzzle.part);
+            }
+            _ => days.push(Listing {
+                year: puzzle.year,
+                day: puzzle.day,
+                parts: vec![puzzle.part],
+            }),
+        }
+    }
+    Response::json(200, &days)
+}
+
+fn read_body(request: &mut Request, max_input_size: usize) -> Result<String, Response> {
+    let too_large = || Response::error(413, &format!("Input larger than {max_input_size} bytes!"));
+    if request
+        .body_length()
+        .is_some_and(|length| length > max_input_size)
+    {
+        return Err(too_large());
+    }
+
+    // the body length is only a hint, chunked bodies don't have one
+    let mut body = Vec::new();
+    request
+        .as_reader()
+        .take(max_input_size as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|err| Response::error(400, &err))?;
+    if body.len() > max_input_size {
+        return Err(too_large());
+    }
+    String::from_utf8(body).map_err(|_| Response::error(400, &"Input is not UTF-8!"))
+}
+
+fn solve(config: &ServeConfig, puzzle: Puzzle, input: &str) -> Response {
+    match runner::solutions(&config.bin_dir) {
+        Ok(solutions) if solutions.contains(&puzzle) => {}
+        Ok(_) => return Response::error(404, &format!("No Solution for {puzzle}!")),
+        Err(err) => return Response::error(500, &err),
+    }
+
+    match runner::run_solution_timed(&config.bin_dir, puzzle, input, Some(config.time_budget)) {
+        Ok(run) => Response::json(
+            200,
+            &Solved {
+                year: puzzle.year,
+                day: puzzle.day,
+                part: puzzle.part,
+                answer: run.answer,
+                elapsed_ms: run.elapsed.as_secs_f64() * 1000.0,
+            },
+        ),
+        Err(err) if err.is::<Timeout>() => Response::error(504, &err),
+        Err(err) => Response::error(422, &err),
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    fmt::{Debug, Write},
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable that makes failing snapshot assertions overwrite the recorded snapshot.
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Extension of recorded snapshots, pending (not yet accepted) ones get `.new` appended.
+const EXTENSION: &str = "snap";
+const PENDING_EXTENSION: &str = "snap.new";
+
+/// Directory the snapshots of this crate are recorded in.
+#[must_use]
+pub fn snapshot_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots")
+}
+
+fn update_requested() -> bool {
+    std::env::var_os(UPDATE_ENV).is_some_and(|value| value != "0")
+}
+
+/// Compares the `Debug` rendering of the value with the recorded snapshot of that name.
+///
+/// A missing or different snapshot is written next to the recorded one as `<name>.snap.new`
+/// and the assertion fails with a diff, accept it with `cargo run --bin accept_snapshots`.
+/// With `UPDATE_SNAPSHOTS=1` set the recorded snapshot is overwritten instead.
+///
+/// # Panics
+///
+/// If the rendering doesn't match the recorded snapshot.
+#[track_caller]
+pub fn assert_debug_snapshot(name: &str, value: &impl Debug) {
+    let actual = format!("{value:?}");
+    if let Err(err) = check_snapshot(&snapshot_dir(), name, &actual, update_requested()) {
+        panic!("{err}");
+    }
+}
+
+/// Checks the contents against the snapshot in the directory, see [`assert_debug_snapshot`].
+///
+/// # Errors
+///
+/// If the snapshot is missing or differs (and isn't updated), or on I/O errors.
+pub fn check_snapshot(dir: &Path, name: &str, actual: &str, update: bool) -> anyhow::Result<()> {
+    let path = dir.join(format!("{name}.{EXTENSION}"));
+    let pending = dir.join(format!("{name}.{PENDING_EXTENSION}"));
+    let expected = fs::read_to_string(&path).ok();
+
+    if expected.as_deref() == Some(actual) {
+        // a stale pending snapshot would be accepted by mistake later
+        if pending.exists() {
+            fs::remove_file(&pending)?;
+        }
+        return Ok(());
+    }
+
+    fs::create_dir_all(dir)?;
+    if update {
+        fs::write(&path, actual)?;
+        return Ok(());
+    }
+    fs::write(&pending, actual)?;
+
+    match expected {
+        Some(expected) => Err(anyhow::anyhow!(
+            "Snapshot {name} doesn't match, review {} and accept it with \
+             `cargo run --bin accept_snapshots`:\n{}",
+            pending.display(),
+            diff_lines(&expected, actual)
+        )),
+        None => Err(anyhow::anyhow!(
+            "Snapshot {name} is missing, review {} and accept it with \
+             `cargo run --bin accept_snapshots`",
+            pending.display()
+        )),
+    }
+}
+
+/// Replaces recorded snapshots with all pending ones in the directory, returns the accepted names.
+///
+/// # Errors
+///
+/// On I/O errors.
+pub fn accept_pending(dir: &Path) -> anyhow::Result<Vec<String>> {
+    let mut accepted = Vec::new();
+    if !dir.exists() {
+        return Ok(accepted);
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(name) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(&format!(".{PENDING_EXTENSION}")))
+        else {
+            continue;
+        };
+        let name = name.to_string();
+        fs::rename(&path, dir.join(format!("{name}.{EXTENSION}")))?;
+        accepted.push(name);
+    }
+
+    accepted.sort();
+    Ok(accepted)
+}
+
+/// Line based diff using the longest common subsequence, removed lines are prefixed
+/// with `-`, added lines with `+` and unchanged lines with a space.
+#[must_use]
+pub fn diff_lines(expected: &str, actual: &str) -> String {
+    let a = expected.lines().collect::<Vec<_>>();
+    let b = actual.lines().collect::<Vec<_>>();
+
+    // lengths of the longest common subsequences of all suffixes
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            let _ = writeln!(diff, " {}", a[i]);
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            let _ = writeln!(diff, "-{}", a[i]);
+            i += 1;
+        } else {
+            let _ = writeln!(diff, "+{}", b[j]);
+            j += 1;
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{accept_pending, check_snapshot, diff_lines};
+
+    #[test]
+    fn test_diff_lines() {
+        assert_eq!(diff_lines("a\nb\nc", "a\nb\nc"), " a\n b\n c\n");
+        assert_eq!(diff_lines("a\nb\nc", "a\nx\nc"), " a\n-b\n+x\n c\n");
+        assert_eq!(diff_lines("a\nb", "a\nb\nc"), " a\n b\n+c\n");
+        assert_eq!(diff_lines("a\nb", "b"), "-a\n b\n");
+    }
+
+    #[test]
+    fn test_check_and_accept() {
+        let dir = std::env::temp_dir().join(format!("snapshot-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        // missing snapshots fail and are written as pending
+        assert!(check_snapshot(&dir, "grid", "..#\n", false).is_err());
+        assert!(dir.join("grid.snap.new").exists());
+        assert_eq!(accept_pending(&dir).unwrap(), ["grid"]);
+        assert!(check_snapshot(&dir, "grid", "..#\n", false).is_ok());
+
+        // changes fail with a diff until updated
+        let err = check_snapshot(&dir, "grid", ".##\n", false).unwrap_err();
+        assert!(err.to_string().contains("-..#\n+.##"));
+        assert!(check_snapshot(&dir, "grid", ".##\n", true).is_ok());
+        assert!(check_snapshot(&dir, "grid", ".##\n", false).is_ok());
+        assert!(!dir.join("grid.snap.new").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,748 @@
+use alloc::{collections::VecDeque, format, string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+/// Zeros of the decimal digits of all scripts in Unicode 14, each followed by the digits from
+/// one to nine.
+const DIGIT_ZEROS: [char; 66] = [
+    '\u{30}',
+    '\u{660}',
+    '\u{6F0}',
+    '\u{7C0}',
+    '\u{966}',
+    '\u{9E6}',
+    '\u{A66}',
+    '\u{AE6}',
+    '\u{B66}',
+    '\u{BE6}',
+    '\u{C66}',
+    '\u{CE6}',
+    '\u{D66}',
+    '\u{DE6}',
+    '\u{E50}',
+    '\u{ED0}',
+    '\u{F20}',
+    '\u{1040}',
+    '\u{1090}',
+    '\u{17E0}',
+    '\u{1810}',
+    '\u{1946}',
+    '\u{19D0}',
+    '\u{1A80}',
+    '\u{1A90}',
+    '\u{1B50}',
+    '\u{1BB0}',
+    '\u{1C40}',
+    '\u{1C50}',
+    '\u{A620}',
+    '\u{A8D0}',
+    '\u{A900}',
+    '\u{A9D0}',
+    '\u{A9F0}',
+    '\u{AA50}',
+    '\u{ABF0}',
+    '\u{FF10}',
+    '\u{104A0}',
+    '\u{10D30}',
+    '\u{11066}',
+    '\u{110F0}',
+    '\u{11136}',
+    '\u{111D0}',
+    '\u{112F0}',
+    '\u{11450}',
+    '\u{114D0}',
+    '\u{11650}',
+    '\u{116C0}',
+    '\u{11730}',
+    '\u{118E0}',
+    '\u{11950}',
+    '\u{11C50}',
+    '\u{11D50}',
+    '\u{11DA0}',
+    '\u{16A60}',
+    '\u{16AC0}',
+    '\u{16B50}',
+    '\u{1D7CE}',
+    '\u{1D7D8}',
+    '\u{1D7E2}',
+    '\u{1D7EC}',
+    '\u{1D7F6}',
+    '\u{1E140}',
+    '\u{1E2F0}',
+    '\u{1E950}',
+    '\u{1FBF0}',
+];
+
+/// Value of a decimal digit of any script, like `7`, `٧` (Arabic-Indic) or `７` (full-width).
+#[must_use]
+pub fn digit_value(character: char) -> Option<u8> {
+    let index = DIGIT_ZEROS.partition_point(|&zero| zero <= character);
+    let zero = *DIGIT_ZEROS.get(index.checked_sub(1)?)?;
+    u8::try_from(u32::from(character) - u32::from(zero))
+        .ok()
+        .filter(|&value| value < 10)
+}
+
+/// Number words from zero to nine in English.
+pub const ENGLISH: [(&str, u8); 10] = [
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// Number words from ten to nineteen in English.
+pub const ENGLISH_TEENS: [(&str, u8); 10] = [
+    ("ten", 10),
+    ("eleven", 11),
+    ("twelve", 12),
+    ("thirteen", 13),
+    ("fourteen", 14),
+    ("fifteen", 15),
+    ("sixteen", 16),
+    ("seventeen", 17),
+    ("eighteen", 18),
+    ("nineteen", 19),
+];
+
+/// Tens from twenty to ninety in English.
+pub const ENGLISH_TENS: [(&str, u8); 8] = [
+    ("twenty", 20),
+    ("thirty", 30),
+    ("forty", 40),
+    ("fifty", 50),
+    ("sixty", 60),
+    ("seventy", 70),
+    ("eighty", 80),
+    ("ninety", 90),
+];
+
+/// Number words from zero to nine in German.
+pub const GERMAN: [(&str, u8); 10] = [
+    ("null", 0),
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+/// Number words from zero to nine in French.
+pub const FRENCH: [(&str, u8); 10] = [
+    ("zéro", 0),
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+];
+
+/// Number words from zero to nine in Spanish.
+pub const SPANISH: [(&str, u8); 10] = [
+    ("cero", 0),
+    ("uno", 1),
+    ("dos", 2),
+    ("tres", 3),
+    ("cuatro", 4),
+    ("cinco", 5),
+    ("seis", 6),
+    ("siete", 7),
+    ("ocho", 8),
+    ("nueve", 9),
+];
+
+/// Multi-token numbers joining each of the tens with each of the units by the separator,
+/// like `twenty-one` for 21.
+#[must_use]
+pub fn compounds(tens: &[(&str, u8)], units: &[(&str, u8)], separator: &str) -> Vec<(String, u8)> {
+    tens.iter()
+        .flat_map(|(ten, ten_value)| {
+            units.iter().map(move |(unit, unit_value)| {
+                (format!("{ten}{separator}{unit}"), ten_value + unit_value)
+            })
+        })
+        .collect()
+}
+
+/// The character in lower case if asked to and it is a single character in lower case.
+fn fold(character: char, ignore_case: bool) -> char {
+    if !ignore_case {
+        return character;
+    }
+    let mut lower = character.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(lower), None) => lower,
+        _ => character,
+    }
+}
+
+/// How matches of the words may overlap each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverlapMode {
+    /// Of the words starting at the same position the longest counts, and a word may
+    /// overlap the last character of the previous one, so `eightwo` is 8 and 2
+    #[default]
+    Overlapping,
+    /// Of the words starting at the same position the longest counts, and words don't
+    /// overlap, so `eightwo` is only 8
+    LeftmostLongest,
+    /// Of the words starting at the same position the one given first to the vocabulary
+    /// counts, and words don't overlap
+    LeftmostFirst,
+}
+
+impl FromStr for OverlapMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "overlapping" => Ok(Self::Overlapping),
+            "leftmost-longest" => Ok(Self::LeftmostLongest),
+            "leftmost-first" => Ok(Self::LeftmostFirst),
+            _ => Err(format!(
+                "Invalid Overlap Mode {s}! (overlapping, leftmost-longest, leftmost-first)"
+            )),
+        }
+    }
+}
+
+/// Word ending in a state of an automaton.
+#[derive(Debug, Clone, Copy)]
+struct Output {
+    /// Length in characters
+    len: usize,
+    value: u8,
+    /// Position of the word in the vocabulary
+    priority: usize,
+}
+
+/// State of an automaton, the root being the first one.
+#[derive(Debug, Clone, Default)]
+struct State {
+    /// Sorted by character
+    transitions: Vec<(char, usize)>,
+    /// Longest proper suffix of the state that is a state as well
+    fail: usize,
+    /// Length in characters of the prefix the state represents
+    depth: usize,
+    /// Every word ending in the state, longest first
+    outputs: Vec<Output>,
+}
+
+impl State {
+    fn transition(&self, character: char) -> Option<usize> {
+        self.transitions
+            .binary_search_by_key(&character, |(character, _)| *character)
+            .ok()
+            .map(|index| self.transitions[index].1)
+    }
+}
+
+/// Aho-Corasick automaton finding all the words in a single pass.
+#[derive(Debug, Clone)]
+struct Automaton {
+    states: Vec<State>,
+}
+
+impl Automaton {
+    fn new(words: &[(Vec<char>, u8)]) -> Self {
+        let mut automaton = Self {
+            states: alloc::vec![State::default()],
+        };
+
+        // the trie of the words
+        for (priority, (word, value)) in words.iter().enumerate() {
+            let mut state = 0;
+            for &character in word {
+                state = if let Some(next) = automaton.states[state].transition(character) {
+                    next
+                } else {
+                    let next = automaton.states.len();
+                    let depth = automaton.states[state].depth + 1;
+                    automaton.states.push(State {
+                        depth,
+                        ..State::default()
+                    });
+                    let transitions = &mut automaton.states[state].transitions;
+                    let index = transitions.partition_point(|(c, _)| *c < character);
+                    transitions.insert(index, (character, next));
+                    next
+                };
+            }
+            if state != 0 {
+                let outputs = &mut automaton.states[state].outputs;
+                let priority = outputs.first().map_or(priority, |output| output.priority);
+                *outputs = alloc::vec![Output {
+                    len: word.len(),
+                    value: *value,
+                    priority,
+                }];
+            }
+        }
+
+        // failure links breadth first, so the links of shallower states are known
+        let mut queue = VecDeque::from([0]);
+        while let Some(state) = queue.pop_front() {
+            for (character, next) in automaton.states[state].transitions.clone() {
+                let fail = if state == 0 {
+                    0
+                } else {
+                    automaton.step(automaton.states[state].fail, character)
+                };
+                let inherited = automaton.states[fail].outputs.clone();
+                automaton.states[next].fail = fail;
+                automaton.states[next].outputs.extend(inherited);
+                queue.push_back(next);
+            }
+        }
+
+        automaton
+    }
+
+    fn step(&self, mut state: usize, character: char) -> usize {
+        loop {
+            if let Some(next) = self.states[state].transition(character) {
+                return next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.states[state].fail;
+        }
+    }
+
+    /// Length in characters of the longest word.
+    fn max_len(&self) -> usize {
+        self.states
+            .iter()
+            .map(|state| state.depth)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Words with the number each of them stands for, searched for all at once with an
+/// Aho-Corasick automaton. Decimal digits of any script stand for themselves, see
+/// [`digit_value`].
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    forward: Automaton,
+    /// Of the reversed words, to scan from the end
+    backward: Automaton,
+    ignore_case: bool,
+    overlap: OverlapMode,
+}
+
+impl Default for Vocabulary {
+    /// The English words from one to nine of the puzzle.
+    fn default() -> Self {
+        Self::new(ENGLISH[1..].iter().copied())
+    }
+}
+
+impl Vocabulary {
+    /// Vocabulary matching the words exactly. A word given more than once stands for the
+    /// last value.
+    #[must_use]
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = (S, u8)>) -> Self {
+        Self::build(words, false)
+    }
+
+    /// Vocabulary matching the words regardless of their case.
+    #[must_use]
+    pub fn case_insensitive<S: AsRef<str>>(words: impl IntoIterator<Item = (S, u8)>) -> Self {
+        Self::build(words, true)
+    }
+
+    fn build<S: AsRef<str>>(words: impl IntoIterator<Item = (S, u8)>, ignore_case: bool) -> Self {
+        let words = words
+            .into_iter()
+            .map(|(word, value)| {
+                let word = word.as_ref().chars().map(|c| fold(c, ignore_case));
+                (word.collect::<Vec<_>>(), value)
+            })
+            .collect::<Vec<_>>();
+        let reversed = words
+            .iter()
+            .map(|(word, value)| (word.iter().rev().copied().collect(), *value))
+            .collect::<Vec<_>>();
+        Self {
+            forward: Automaton::new(&words),
+            backward: Automaton::new(&reversed),
+            ignore_case,
+            overlap: OverlapMode::default(),
+        }
+    }
+
+    /// The vocabulary with matches overlapping as given.
+    #[must_use]
+    pub fn overlap(mut self, overlap: OverlapMode) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Numbers in the haystack from left to right, overlapping as set by
+    /// [`Vocabulary::overlap`].
+    #[must_use]
+    pub fn matches<'a>(&'a self, haystack: &'a str) -> Matches<'a> {
+        let max_len = self.forward.max_len();
+        Matches {
+            vocabulary: self,
+            characters: haystack.char_indices(),
+            state: 0,
+            offsets: VecDeque::with_capacity(max_len),
+            max_len,
+            position: 0,
+            min_start: 0,
+            candidates: Vec::new(),
+        }
+    }
+
+    /// The last of the [`Vocabulary::matches`], scanning backwards from the end of the
+    /// haystack only until the matches before can't change it anymore: the first position
+    /// before the last match that no word spans across.
+    #[must_use]
+    pub fn last_match(&self, haystack: &str) -> Option<Match> {
+        let mut state = 0;
+        let mut found = false;
+        for (offset, character) in haystack.char_indices().rev() {
+            state = self.backward.step(state, fold(character, self.ignore_case));
+            found |=
+                digit_value(character).is_some() || !self.backward.states[state].outputs.is_empty();
+            if found && self.backward.states[state].depth == 0 {
+                return self.matches(&haystack[offset..]).last().map(|found| Match {
+                    start: offset + found.start,
+                    end: offset + found.end,
+                    ..found
+                });
+            }
+        }
+        self.matches(haystack).last()
+    }
+}
+
+/// How a number is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchKind {
+    Digit,
+    Word,
+}
+
+impl fmt::Display for MatchKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Digit => write!(f, "digit"),
+            Self::Word => write!(f, "word"),
+        }
+    }
+}
+
+/// Number found in a haystack, `start..end` being its byte range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u8,
+    pub kind: MatchKind,
+}
+
+/// Match found but not yet returned.
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    found: Match,
+    /// Byte offset of the last character
+    last: usize,
+    /// Position of the word in the vocabulary, digits coming first
+    priority: usize,
+}
+
+/// Iterator over the numbers of a haystack, see [`Vocabulary::matches`].
+#[derive(Debug, Clone)]
+pub struct Matches<'a> {
+    vocabulary: &'a Vocabulary,
+    characters: core::str::CharIndices<'a>,
+    state: usize,
+    /// Byte offsets of the last characters, as many as the longest word has
+    offsets: VecDeque<usize>,
+    max_len: usize,
+    /// Byte offset after the last character read
+    position: usize,
+    /// Matches have to start here or later not to overlap the previous one too much
+    min_start: usize,
+    candidates: Vec<Candidate>,
+}
+
+impl Matches<'_> {
+    /// The leftmost of the candidates, of those starting there the one the overlap mode
+    /// prefers.
+    fn leftmost(&self) -> Option<usize> {
+        (0..self.candidates.len()).min_by(|&a, &b| {
+            let (a, b) = (self.candidates[a], self.candidates[b]);
+            a.found
+                .start
+                .cmp(&b.found.start)
+                .then_with(|| match self.vocabulary.overlap {
+                    OverlapMode::Overlapping | OverlapMode::LeftmostLongest => {
+                        b.found.end.cmp(&a.found.end)
+                    }
+                    OverlapMode::LeftmostFirst => a.priority.cmp(&b.priority),
+                })
+        })
+    }
+
+    /// Reads the next character, returns false at the end of the haystack.
+    fn read(&mut self) -> bool {
+        let Some((offset, character)) = self.characters.next() else {
+            return false;
+        };
+        self.position = offset + character.len_utf8();
+        if self.offsets.len() == self.max_len {
+            self.offsets.pop_front();
+        }
+        if self.max_len > 0 {
+            self.offsets.push_back(offset);
+        }
+
+        if let Some(value) = digit_value(character).filter(|_| offset >= self.min_start) {
+            self.candidates.push(Candidate {
+                found: Match {
+                    start: offset,
+                    end: self.position,
+                    value,
+                    kind: MatchKind::Digit,
+                },
+                last: offset,
+                priority: 0,
+            });
+        }
+
+        let vocabulary = self.vocabulary;
+        let automaton = &vocabulary.forward;
+        self.state = automaton.step(self.state, fold(character, vocabulary.ignore_case));
+        for output in &automaton.states[self.state].outputs {
+            let start = self.offsets[self.offsets.len() - output.len];
+            if start >= self.min_start {
+                self.candidates.push(Candidate {
+                    found: Match {
+                        start,
+                        end: self.position,
+                        value: output.value,
+                        kind: MatchKind::Word,
+                    },
+                    last: offset,
+                    priority: output.priority + 1,
+                });
+            }
+        }
+        true
+    }
+}
+
+impl Iterator for Matches<'_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        loop {
+            let exhausted = !self.read();
+            if let Some(index) = self.leftmost() {
+                // words that are still being read start at the frontier or later
+                let depth = self.vocabulary.forward.states[self.state].depth;
+                let frontier = if exhausted {
+                    usize::MAX
+                } else if depth == 0 {
+                    self.position
+                } else {
+                    self.offsets[self.offsets.len() - depth]
+                };
+                let Candidate { found, last, .. } = self.candidates[index];
+                if found.start < frontier {
+                    self.min_start = match self.vocabulary.overlap {
+                        OverlapMode::Overlapping => last.max(found.start + 1),
+                        OverlapMode::LeftmostLongest | OverlapMode::LeftmostFirst => found.end,
+                    };
+                    let min_start = self.min_start;
+                    self.candidates
+                        .retain(|candidate| candidate.found.start >= min_start);
+                    return Some(found);
+                }
+            } else if exhausted {
+                return None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{
+        compounds, digit_value, Match, MatchKind, OverlapMode, Vocabulary, ENGLISH, ENGLISH_TEENS,
+        ENGLISH_TENS, GERMAN,
+    };
+
+    fn values(vocabulary: &Vocabulary, haystack: &str) -> Vec<u8> {
+        vocabulary
+            .matches(haystack)
+            .map(|found| found.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_default() {
+        let vocabulary = Vocabulary::default();
+        assert_eq!(values(&vocabulary, "xtwone3four"), [2, 1, 3, 4]);
+        assert_eq!(values(&vocabulary, "zoneight234"), [1, 8, 2, 3, 4]);
+        assert_eq!(values(&vocabulary, "7pqrstsixteen"), [7, 6]);
+        assert!(values(&vocabulary, "zero").is_empty());
+        assert_eq!(values(&vocabulary, "ONE1"), [1]);
+        assert_eq!(
+            vocabulary.matches("x٧").next().map(|found| found.kind),
+            Some(MatchKind::Digit)
+        );
+        assert_eq!(
+            vocabulary.matches("aeightwo").collect::<Vec<_>>(),
+            [
+                Match {
+                    start: 1,
+                    end: 6,
+                    value: 8,
+                    kind: MatchKind::Word
+                },
+                Match {
+                    start: 5,
+                    end: 8,
+                    value: 2,
+                    kind: MatchKind::Word
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_digit_value() {
+        assert_eq!(digit_value('0'), Some(0));
+        assert_eq!(digit_value('9'), Some(9));
+        assert_eq!(digit_value('٣'), Some(3));
+        assert_eq!(digit_value('７'), Some(7));
+        assert_eq!(digit_value('\u{1D7FF}'), Some(9));
+        assert_eq!(digit_value('a'), None);
+        assert_eq!(digit_value('/'), None);
+        assert_eq!(digit_value('²'), None);
+        assert_eq!(digit_value('\u{6FA}'), None);
+    }
+
+    #[test]
+    fn test_unicode() {
+        let vocabulary = Vocabulary::default();
+        assert_eq!(values(&vocabulary, "ünë٣two７"), [3, 2, 7]);
+        assert_eq!(values(&vocabulary, "🎄eight🎄wo"), [8]);
+    }
+
+    #[test]
+    fn test_languages() {
+        let vocabulary = Vocabulary::case_insensitive(GERMAN);
+        assert_eq!(values(&vocabulary, "FÜNFundNeunzig0"), [5, 9, 0]);
+        assert_eq!(values(&vocabulary, "achtzehn"), [8]);
+
+        let vocabulary = Vocabulary::new(ENGLISH);
+        assert_eq!(values(&vocabulary, "zerone"), [0, 1]);
+        assert!(values(&vocabulary, "ZERO").is_empty());
+    }
+
+    #[test]
+    fn test_overlap() {
+        let overlapping = Vocabulary::default();
+        let longest = Vocabulary::default().overlap(OverlapMode::LeftmostLongest);
+        assert_eq!(values(&overlapping, "eightwone"), [8, 2, 1]);
+        assert_eq!(values(&longest, "eightwone"), [8, 1]);
+        assert_eq!(values(&longest, "twone"), [2]);
+
+        let words = [("seven", 7), ("seventeen", 17), ("teen", 0)];
+        let longest = Vocabulary::new(words).overlap(OverlapMode::LeftmostLongest);
+        let first = Vocabulary::new(words).overlap(OverlapMode::LeftmostFirst);
+        assert_eq!(values(&longest, "seventeen"), [17]);
+        assert_eq!(values(&first, "seventeen"), [7, 0]);
+        assert_eq!("leftmost-first".parse(), Ok(OverlapMode::LeftmostFirst));
+        assert!("rightmost".parse::<OverlapMode>().is_err());
+    }
+
+    #[test]
+    fn test_last_match() {
+        let words = ENGLISH
+            .into_iter()
+            .chain(ENGLISH_TEENS)
+            .chain(ENGLISH_TENS)
+            .map(|(word, value)| (word.into(), value))
+            .chain(compounds(&ENGLISH_TENS, &ENGLISH[1..], "-"))
+            .collect::<Vec<_>>();
+        let haystacks = [
+            "",
+            "abc",
+            "xtwone3four",
+            "zoneight234",
+            "eightwo",
+            "eightwone",
+            "nineighteenine",
+            "twenty-one",
+            "4twenty-ninety-nineight",
+            "seventeenineteen٣x",
+            "ｏｎｅ",
+        ];
+        for overlap in [
+            OverlapMode::Overlapping,
+            OverlapMode::LeftmostLongest,
+            OverlapMode::LeftmostFirst,
+        ] {
+            for vocabulary in [Vocabulary::default(), Vocabulary::new(words.clone())] {
+                let vocabulary = vocabulary.overlap(overlap);
+                for haystack in haystacks {
+                    assert_eq!(
+                        vocabulary.last_match(haystack),
+                        vocabulary.matches(haystack).last(),
+                        "{haystack} {overlap:?}"
+                    );
+                }
+            }
+        }
+        assert_eq!(
+            Vocabulary::default().last_match("a1twoneb"),
+            Some(Match {
+                start: 4,
+                end: 7,
+                value: 1,
+                kind: MatchKind::Word
+            })
+        );
+    }
+
+    #[test]
+    fn test_compounds() {
+        let words = ENGLISH
+            .into_iter()
+            .chain(ENGLISH_TEENS)
+            .chain(ENGLISH_TENS)
+            .map(|(word, value)| (word.into(), value))
+            .chain(compounds(&ENGLISH_TENS, &ENGLISH[1..], "-"))
+            .collect::<Vec<_>>();
+        let vocabulary = Vocabulary::new(words);
+        assert_eq!(values(&vocabulary, "twenty-one"), [21]);
+        assert_eq!(values(&vocabulary, "twenty-onetwenty"), [21, 20]);
+        assert_eq!(values(&vocabulary, "seventeen4"), [17, 4]);
+        assert_eq!(values(&vocabulary, "ninety-nineight"), [99, 8]);
+        assert_eq!(values(&vocabulary, "twenty-x"), [20]);
+    }
+}
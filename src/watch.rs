@@ -0,0 +1,146 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{
+    answers::Verdict,
+    input::{input_path, INPUT_DIR},
+    puzzle::Puzzle,
+    scaffold::solver_path,
+};
+
+/// Modification time of each watched file, `None` while the file doesn't exist.
+pub type Stamps = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// Files the solutions of the day depend on: the solvers of both parts, the cached input
+/// and the example files of the day.
+///
+/// # Errors
+///
+/// If the example directory of the day can't be read.
+pub fn watched_files(
+    root: &Path,
+    examples_dir: &Path,
+    year: u16,
+    day: u8,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = (1..=2)
+        .map(|part| root.join(solver_path(Puzzle::new(year, day, part))))
+        .collect::<Vec<_>>();
+
+    let input = input_path(&root.join(INPUT_DIR), year, day);
+    #[cfg(feature = "encrypt")]
+    files.push(crate::input::encrypted_path(&input));
+    files.push(input);
+
+    let day_dir = examples_dir
+        .join(year.to_string())
+        .join(format!("day{day}"));
+    if day_dir.is_dir() {
+        for entry in fs::read_dir(day_dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "txt") {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Current modification times of the files.
+#[must_use]
+pub fn stamps(files: &[PathBuf]) -> Stamps {
+    files
+        .iter()
+        .map(|file| {
+            let modified = fs::metadata(file).and_then(|metadata| metadata.modified());
+            (file.clone(), modified.ok())
+        })
+        .collect()
+}
+
+/// Files that were added, removed or modified between the two stamps, sorted.
+#[must_use]
+pub fn changed(before: &Stamps, after: &Stamps) -> Vec<PathBuf> {
+    before
+        .keys()
+        .chain(after.keys())
+        .filter(|file| before.get(*file) != after.get(*file))
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// The answer with how it differs from the answer of the previous run and the recorded one,
+/// like `42 (was 41, correct)`.
+#[must_use]
+pub fn compare(answer: &str, previous: Option<&str>, verdict: Verdict) -> String {
+    let run = match previous {
+        None => "first run".to_string(),
+        Some(previous) if previous == answer => "unchanged".to_string(),
+        Some(previous) => format!("was {previous}"),
+    };
+    let recorded = match verdict {
+        Verdict::Correct => "correct",
+        Verdict::Wrong => "differs from the recorded answer",
+        Verdict::Unknown => "no recorded answer",
+    };
+    format!("{answer} ({run}, {recorded})")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::answers::Verdict;
+
+    use super::{changed, compare, stamps, watched_files};
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(
+            compare("42", None, Verdict::Unknown),
+            "42 (first run, no recorded answer)"
+        );
+        assert_eq!(
+            compare("42", Some("42"), Verdict::Correct),
+            "42 (unchanged, correct)"
+        );
+        assert_eq!(
+            compare("42", Some("41"), Verdict::Wrong),
+            "42 (was 41, differs from the recorded answer)"
+        );
+    }
+
+    #[test]
+    fn test_changed() {
+        let root = std::env::temp_dir().join(format!("watch-test-{}", std::process::id()));
+        let examples = root.join("examples");
+        fs::create_dir_all(examples.join("2023").join("day3")).unwrap();
+        fs::create_dir_all(root.join("src/year2023/day3")).unwrap();
+
+        let files = watched_files(&root, &examples, 2023, 3).unwrap();
+        assert!(files.contains(&root.join("src/year2023/day3/part2.rs")));
+        assert!(files.contains(&root.join("inputs/2023/day3.txt")));
+        let before = stamps(&files);
+        assert!(before.values().all(Option::is_none));
+
+        let source = root.join("src/year2023/day3/part1.rs");
+        fs::write(&source, "pub fn solve() {}").unwrap();
+        let example = examples.join("2023/day3/example1.txt");
+        fs::write(&example, "# part1: 4361\n").unwrap();
+
+        let after = stamps(&watched_files(&root, &examples, 2023, 3).unwrap());
+        assert_eq!(changed(&before, &after), [example, source]);
+        assert_eq!(changed(&after, &before), changed(&before, &after));
+        assert!(changed(&after, &after).is_empty());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
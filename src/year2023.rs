@@ -0,0 +1,74 @@
+//! Parsing and solving of the 2023 puzzles, the binaries of the days only read the input,
+//! print the answers and offer their REPLs.
+
+// the solutions panic on malformed puzzle input and cast freely between grid coordinates,
+// as they did in the binaries
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss,
+    clippy::missing_errors_doc,
+    clippy::missing_panics_doc,
+    clippy::must_use_candidate
+)]
+
+pub mod day1 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day2 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day3 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day4 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day5 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day6 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day7 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day8 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day9 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day10 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day11 {
+    pub mod part1;
+    pub mod part2;
+}
+
+pub mod day12 {
+    pub mod part1;
+    pub mod part2;
+}
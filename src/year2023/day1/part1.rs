@@ -0,0 +1,65 @@
+use crate::vocabulary::digit_value;
+
+/// Take first and last digit in line and join them into an integer.
+/// Decimal digits of all scripts count, like `٣` or `７`.
+/// Expects the line to contain at least one digit, in which case it is repeated.
+///
+/// # Errors
+///
+/// If the line has no digit.
+pub fn join_first_and_last_digits(line: &str) -> anyhow::Result<u8> {
+    // create iterator of the values of the digit chars
+    let mut iterator = line.chars().filter_map(digit_value);
+
+    // take first digit
+    let first = iterator
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Invalid Input, Lines without digit"))?;
+
+    // take last digit or repeat the first digit if there are no more digits
+    let last = iterator.next_back().unwrap_or(first);
+
+    Ok(first * 10 + last)
+}
+
+/// Sum of the calibration values of the lines.
+///
+/// # Errors
+///
+/// If a line has no digit.
+pub fn sum_calibration_values(contents: &str) -> anyhow::Result<u64> {
+    contents
+        .split_terminator('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            join_first_and_last_digits(line)
+                .map(u64::from)
+                .map_err(|err| anyhow::anyhow!("Line {}: {err}", index + 1))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::join_first_and_last_digits;
+
+    #[test]
+    fn test_join_first_and_last_digits() {
+        assert_eq!(join_first_and_last_digits("42").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("foo42").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("42foo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("4foo2").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("foo4foo2").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("4foo2foo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("foo4foo2foo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("4").unwrap(), 44);
+        assert_eq!(join_first_and_last_digits("foo4").unwrap(), 44);
+        assert_eq!(join_first_and_last_digits("4foo").unwrap(), 44);
+        assert_eq!(join_first_and_last_digits("foo4foo").unwrap(), 44);
+
+        assert_eq!(join_first_and_last_digits("٤ü٢").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("ｆｏｏ４").unwrap(), 44);
+        assert!(join_first_and_last_digits("foo").is_err());
+        assert!(join_first_and_last_digits("").is_err());
+    }
+}
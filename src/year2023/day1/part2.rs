@@ -0,0 +1,349 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::FromStr;
+
+use crate::{
+    answer::Answer,
+    vocabulary::{Matches, Vocabulary},
+};
+
+/// The English words from one to nine of the puzzle.
+#[cfg(test)]
+static PUZZLE_VOCABULARY: std::sync::LazyLock<Vocabulary> =
+    std::sync::LazyLock::new(Vocabulary::default);
+
+pub struct NumberIter<'a> {
+    matches: Matches<'a>,
+}
+
+impl<'a> NumberIter<'a> {
+    #[cfg(test)]
+    fn new(haystack: &'a str) -> Self {
+        Self::with_vocabulary(haystack, &PUZZLE_VOCABULARY)
+    }
+
+    /// Numbers in the haystack, spelled out with the words of the vocabulary or as digits.
+    #[must_use]
+    pub fn with_vocabulary(haystack: &'a str, vocabulary: &'a Vocabulary) -> Self {
+        Self {
+            matches: vocabulary.matches(haystack),
+        }
+    }
+
+    /// The numbers with their spans and whether they are digits or words.
+    #[must_use]
+    pub fn matches(self) -> Matches<'a> {
+        self.matches
+    }
+}
+
+impl Iterator for NumberIter<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.matches.next().map(|found| found.value)
+    }
+}
+
+/// Take the first and last number in line, concat, parse as an integer and return.
+/// Both decimal digits of all scripts and spelled out words (one to nine) count as numbers.
+/// Expects the line to contain at least one number, in which case it is repeated.
+#[cfg(test)]
+fn join_first_and_last_digits(line: &str) -> anyhow::Result<u8> {
+    join_first_and_last_numbers(line, &PUZZLE_VOCABULARY)
+}
+
+/// Like `join_first_and_last_digits` with the numbers of the vocabulary, the default
+/// [`Extraction`].
+#[cfg(test)]
+fn join_first_and_last_numbers(line: &str, vocabulary: &Vocabulary) -> anyhow::Result<u8> {
+    let joined = Extraction::default().extract(line, vocabulary)?;
+
+    // parse the joined numbers as a 8 bit unsigned integer
+    str::parse::<u8>(&joined.to_string()).map_err(Into::into)
+}
+
+/// What the calibration value of a line is made of, the numbers joined as digits unless
+/// summed up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extraction {
+    /// The first and the last k numbers, the puzzle joining the first and the last one
+    FirstAndLast(usize),
+    /// The first k numbers
+    First(usize),
+    /// The last k numbers
+    Last(usize),
+    /// All numbers
+    All,
+    /// Sum of all numbers
+    Sum,
+    /// Largest number all numbers can be joined into
+    Largest,
+}
+
+impl Default for Extraction {
+    fn default() -> Self {
+        Self::FirstAndLast(1)
+    }
+}
+
+impl FromStr for Extraction {
+    type Err = String;
+
+    /// `first-and-last`, `first`, `last` with an optional count like `first:3`, `all`,
+    /// `sum` or `largest`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "Invalid Extraction {s}! \
+                 (first-and-last[:k], first[:k], last[:k], all, sum, largest)"
+            )
+        };
+        let (name, count) = match s.split_once(':') {
+            Some((name, count)) => match count.parse::<usize>() {
+                Ok(count) if count > 0 => (name, Some(count)),
+                _ => return Err(invalid()),
+            },
+            None => (s, None),
+        };
+        match (name, count) {
+            ("first-and-last", count) => Ok(Self::FirstAndLast(count.unwrap_or(1))),
+            ("first", count) => Ok(Self::First(count.unwrap_or(1))),
+            ("last", count) => Ok(Self::Last(count.unwrap_or(1))),
+            ("all", None) => Ok(Self::All),
+            ("sum", None) => Ok(Self::Sum),
+            ("largest", None) => Ok(Self::Largest),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// The numbers joined as digits into a single integer.
+fn concat(numbers: impl IntoIterator<Item = u8>) -> Answer {
+    let digits = numbers
+        .into_iter()
+        .map(|number| number.to_string())
+        .collect::<String>();
+    let Ok(answer) = digits.parse::<Answer>();
+    answer
+}
+
+impl Extraction {
+    /// The calibration value of the line, with the numbers of the vocabulary.
+    ///
+    /// # Errors
+    ///
+    /// If the line has no number.
+    pub fn extract(self, line: &str, vocabulary: &Vocabulary) -> anyhow::Result<Answer> {
+        let no_digit = || anyhow::anyhow!("Invalid Input, Lines without digit");
+        if self == Self::FirstAndLast(1) {
+            // the last number scanning from the end, no need to find those in between
+            let first = NumberIter::with_vocabulary(line, vocabulary)
+                .next()
+                .ok_or_else(no_digit)?;
+            let last = vocabulary
+                .last_match(line)
+                .map_or(first, |found| found.value);
+            return Ok(concat([first, last]));
+        }
+
+        let mut numbers = NumberIter::with_vocabulary(line, vocabulary).collect::<Vec<_>>();
+        if numbers.is_empty() {
+            return Err(no_digit());
+        }
+        let last = |count: usize| &numbers[numbers.len().saturating_sub(count)..];
+        Ok(match self {
+            Self::FirstAndLast(count) => {
+                concat(numbers.iter().take(count).chain(last(count)).copied())
+            }
+            Self::First(count) => concat(numbers.iter().take(count).copied()),
+            Self::Last(count) => concat(last(count).iter().copied()),
+            Self::All => concat(numbers),
+            Self::Sum => numbers.into_iter().map(Answer::from).sum(),
+            Self::Largest => {
+                // a goes before b if it makes the larger number, which isn't always the larger one: 9 before 21
+                numbers.sort_by(|a, b| format!("{b}{a}").cmp(&format!("{a}{b}")));
+                concat(numbers)
+            }
+        })
+    }
+}
+
+/// Sum of the calibration values of the lines, see [`Extraction::extract`].
+///
+/// # Errors
+///
+/// If a line has no number.
+pub fn sum_calibration_values(
+    contents: &str,
+    vocabulary: &Vocabulary,
+    extraction: Extraction,
+) -> anyhow::Result<Answer> {
+    contents
+        .split_terminator('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            extraction
+                .extract(line, vocabulary)
+                .map_err(|err| anyhow::anyhow!("Line {}: {err}", index + 1))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        join_first_and_last_digits, join_first_and_last_numbers, Extraction, NumberIter,
+        PUZZLE_VOCABULARY,
+    };
+    use crate::{
+        answer::Answer,
+        vocabulary::{OverlapMode, Vocabulary},
+    };
+
+    #[test]
+    fn matcher_test() {
+        assert_eq!(NumberIter::new("42").collect::<Vec<_>>(), [4, 2]);
+        assert_eq!(NumberIter::new("foo42").collect::<Vec<_>>(), [4, 2]);
+        assert_eq!(NumberIter::new("42foo").collect::<Vec<_>>(), [4, 2]);
+        assert_eq!(NumberIter::new("4foo2").collect::<Vec<_>>(), [4, 2]);
+        assert_eq!(NumberIter::new("foo4foo2").collect::<Vec<_>>(), [4, 2]);
+        assert_eq!(NumberIter::new("4foo2foo").collect::<Vec<_>>(), [4, 2]);
+        assert_eq!(NumberIter::new("foo4foo2foo").collect::<Vec<_>>(), [4, 2]);
+        assert_eq!(NumberIter::new("4").collect::<Vec<_>>(), [4]);
+        assert_eq!(NumberIter::new("foo4").collect::<Vec<_>>(), [4]);
+        assert_eq!(NumberIter::new("4foo").collect::<Vec<_>>(), [4]);
+        assert_eq!(NumberIter::new("foo4foo").collect::<Vec<_>>(), [4]);
+        assert_eq!(NumberIter::new("foofourtwo").collect::<Vec<_>>(), [4, 2]);
+        assert_eq!(NumberIter::new("4footwo").collect::<Vec<_>>(), [4, 2]);
+        assert_eq!(
+            NumberIter::new("onetwothreefour").collect::<Vec<_>>(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(
+            NumberIter::new("fivesixseveneightnine").collect::<Vec<_>>(),
+            [5, 6, 7, 8, 9]
+        );
+        assert_eq!(NumberIter::new("eightwo").collect::<Vec<_>>(), [8, 2]);
+        assert_eq!(NumberIter::new("zero").next(), None);
+    }
+
+    #[test]
+    fn vocabulary_test() {
+        use crate::vocabulary::{compounds, ENGLISH, ENGLISH_TENS, FRENCH, SPANISH};
+
+        let french = Vocabulary::case_insensitive(FRENCH);
+        assert_eq!(
+            NumberIter::with_vocabulary("DeuxZéro9", &french).collect::<Vec<_>>(),
+            [2, 0, 9]
+        );
+        let spanish = Vocabulary::new(SPANISH);
+        assert_eq!(
+            NumberIter::with_vocabulary("cincosiete", &spanish).collect::<Vec<_>>(),
+            [5, 7]
+        );
+        let english = Vocabulary::new(
+            ENGLISH
+                .map(|(word, value)| (word.to_string(), value))
+                .into_iter()
+                .chain(compounds(&ENGLISH_TENS, &ENGLISH[1..], "-")),
+        );
+        assert_eq!(
+            NumberIter::with_vocabulary("zerotwenty-one", &english).collect::<Vec<_>>(),
+            [0, 21]
+        );
+    }
+
+    #[test]
+    fn test_join_first_and_last_digits() {
+        assert_eq!(join_first_and_last_digits("42").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("foo42").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("42foo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("4foo2").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("foo4foo2").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("4foo2foo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("foo4foo2foo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("4").unwrap(), 44);
+        assert_eq!(join_first_and_last_digits("foo4").unwrap(), 44);
+        assert_eq!(join_first_and_last_digits("4foo").unwrap(), 44);
+        assert_eq!(join_first_and_last_digits("foo4foo").unwrap(), 44);
+
+        assert_eq!(join_first_and_last_digits("fourtwo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("foofourtwo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("fourtwofoo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("fourfootwo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("foofourfootwo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("fourfootwofoo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("foofourfootwofoo").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("four").unwrap(), 44);
+        assert_eq!(join_first_and_last_digits("foofour").unwrap(), 44);
+        assert_eq!(join_first_and_last_digits("fourfoo").unwrap(), 44);
+        assert_eq!(join_first_and_last_digits("foofourfoo").unwrap(), 44);
+
+        assert_eq!(join_first_and_last_digits("two1nine").unwrap(), 29);
+        assert_eq!(join_first_and_last_digits("eightwothree").unwrap(), 83);
+        assert_eq!(join_first_and_last_digits("abcone2threexyz").unwrap(), 13);
+        assert_eq!(join_first_and_last_digits("xtwone3four").unwrap(), 24);
+        assert_eq!(join_first_and_last_digits("4nineeightseven2").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("zoneight234").unwrap(), 14);
+        assert_eq!(join_first_and_last_digits("7pqrstsixteen").unwrap(), 76);
+
+        assert_eq!(join_first_and_last_digits("ünë٤two").unwrap(), 42);
+        assert_eq!(join_first_and_last_digits("４ｔｗｏ").unwrap(), 44);
+        assert!(join_first_and_last_digits("zero").is_err());
+
+        let longest = Vocabulary::default().overlap(OverlapMode::LeftmostLongest);
+        assert_eq!(
+            join_first_and_last_numbers("eightwo", &longest).unwrap(),
+            88
+        );
+        assert_eq!(
+            join_first_and_last_numbers("xtwone3four", &longest).unwrap(),
+            24
+        );
+    }
+
+    #[test]
+    fn test_extract() {
+        use crate::vocabulary::{compounds, ENGLISH, ENGLISH_TENS};
+
+        let extract = |extraction: &str, line: &str| {
+            let extraction = extraction.parse::<Extraction>().unwrap();
+            extraction
+                .extract(line, &PUZZLE_VOCABULARY)
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(extract("first-and-last", "two1nine"), "29");
+        assert_eq!(extract("first-and-last", "7pqrstsixteen"), "76");
+        assert_eq!(extract("first-and-last", "treb7uchet"), "77");
+        assert_eq!(extract("first:2", "two1nine"), "21");
+        assert_eq!(extract("last:2", "two1nine"), "19");
+        assert_eq!(extract("first-and-last:2", "4nineeightseven2"), "4972");
+        assert_eq!(extract("first:3", "0x5"), "5");
+        assert_eq!(extract("all", "4nineeightseven2"), "49872");
+        assert_eq!(extract("sum", "4nineeightseven2"), "30");
+        assert_eq!(extract("largest", "4nineeightseven2"), "98742");
+        assert_eq!(extract("all", &"nine".repeat(30)), "9".repeat(30));
+
+        let english = Vocabulary::new(
+            ENGLISH
+                .map(|(word, value)| (word.to_string(), value))
+                .into_iter()
+                .chain(compounds(&ENGLISH_TENS, &ENGLISH[1..], "-")),
+        );
+        let largest = Extraction::Largest
+            .extract("ninetwenty-onethree", &english)
+            .unwrap();
+        assert_eq!(largest, Answer::from(9321u16));
+        assert!(Extraction::Sum.extract("xyz", &english).is_err());
+
+        assert_eq!("first-and-last".parse(), Ok(Extraction::default()));
+        assert!("first:0".parse::<Extraction>().is_err());
+        assert!("sum:2".parse::<Extraction>().is_err());
+        assert!("median".parse::<Extraction>().is_err());
+    }
+}
@@ -1,10 +1,9 @@
-use std::{
-    collections::{HashSet, VecDeque},
-    fs::read_to_string,
-};
+use alloc::vec::Vec;
+
+use crate::graph;
 
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     tiles: Vec<Vec<char>>,
     width: usize,
     height: usize,
@@ -37,7 +36,7 @@ impl Map {
         ('F', (Self::SOUTH, Self::EAST)),
     ];
 
-    fn from_contents(contents: &str) -> Self {
+    pub fn from_contents(contents: &str) -> Self {
         let mut width = 0;
         let mut height = 0;
         let tiles = contents
@@ -74,7 +73,7 @@ impl Map {
     fn find_connected(&self, tile: (isize, isize)) -> Vec<MapCoord> {
         let mut connected = Vec::new();
         let (x, y) = tile;
-        for (ox, oy) in Self::NEIGHBORS.iter() {
+        for (ox, oy) in Self::NEIGHBORS {
             // inverse offset:
             let offset = (
                 if *ox == 1 { -1 } else { isize::from(*ox == -1) },
@@ -95,56 +94,37 @@ impl Map {
         connected
     }
 
-    // find max steps
-    fn traverse(&self) -> usize {
-        let start = self.find_char('S').expect("No start tile found!");
-
-        // queue with all neighbors connecting to start (coordinate, number-of-steps)
-        let mut q = self
-            .find_connected(start)
+    /// Tiles connected through the pipe on the given tile, the start tile connects
+    /// to every neighbor pipe pointing into it.
+    fn pipe_neighbors(&self, tile: MapCoord) -> Vec<MapCoord> {
+        let Some(ch) = self.get(tile) else {
+            return Vec::new();
+        };
+        if ch == 'S' {
+            return self.find_connected(tile);
+        }
+        Self::PIPES
             .iter()
-            .map(|coord| (*coord, 1))
-            .collect::<VecDeque<_>>();
-        let mut visited = HashSet::new();
-        let mut max_steps = 0;
-
-        while let Some((current, steps)) = q.pop_back() {
-            if steps > max_steps {
-                max_steps = steps;
-            }
-            visited.insert(current);
-            let Some(ch) = self.get(current) else {
-                continue;
-            };
-            if let Some((_, (a, b))) = Self::PIPES.iter().find(|(pipe, _)| *pipe == ch) {
+            .find(|(pipe, _)| *pipe == ch)
+            .map(|(_, (a, b))| {
                 // a and b are offsets indicating the pipe direction
                 // get the absolute coordinates:
-                let a = (current.0 + a.0, current.1 + a.1);
-                let b = (current.0 + b.0, current.1 + b.1);
-
-                if !visited.contains(&a) {
-                    q.push_front((a, steps + 1));
-                }
-                if !visited.contains(&b) {
-                    q.push_front((b, steps + 1));
-                }
-            }
-        }
-
-        max_steps
+                alloc::vec![(tile.0 + a.0, tile.1 + a.1), (tile.0 + b.0, tile.1 + b.1)]
+            })
+            .unwrap_or_default()
     }
-}
 
-fn main() {
-    let contents = read_to_string("day10-input.txt").expect("Invalid Input!");
-    let map = Map::from_contents(&contents);
-    let max_path = map.traverse();
-    println!("Solution: {max_path}");
+    // find max steps
+    pub fn traverse(&self) -> usize {
+        let start = self.find_char('S').expect("No start tile found!");
+        let steps = graph::bfs(start, |tile| self.pipe_neighbors(*tile));
+        steps.into_values().max().unwrap_or(0)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Map;
+    use super::Map;
 
     const EXAMPLE_INPUT_1: &str = "
     .....
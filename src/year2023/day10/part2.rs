@@ -1,10 +1,7 @@
-// #![warn(clippy::pedantic)]
-use core::fmt;
-use std::{
-    collections::{HashSet, VecDeque},
-    fmt::{Debug, Formatter},
-    fs::read_to_string,
-};
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+
+use crate::{collections::HashSet, graph};
 
 type GridCoord = (isize, isize);
 
@@ -14,10 +11,15 @@ fn product_iter(width: usize, height: usize) -> impl Iterator<Item = GridCoord>
 }
 
 const NORTH: GridCoord = (0, -1);
+
 const SOUTH: GridCoord = (0, 1);
+
 const WEST: GridCoord = (-1, 0);
+
 const EAST: GridCoord = (1, 0);
+
 const NEIGHBORS: &[GridCoord] = &[EAST, WEST, NORTH, SOUTH];
+
 const PIPES: &[(char, (GridCoord, GridCoord))] = &[
     // connecting north and south
     ('|', (NORTH, SOUTH)),
@@ -33,7 +35,7 @@ const PIPES: &[(char, (GridCoord, GridCoord))] = &[
     ('F', (SOUTH, EAST)),
 ];
 
-struct Grid {
+pub struct Grid {
     rows: Vec<Vec<char>>,
     width: usize,
     height: usize,
@@ -69,7 +71,7 @@ impl Grid {
 
     fn is_any(&self, x: isize, y: isize, any: &[char]) -> bool {
         if let Some(ch) = self.get(x, y) {
-            any.iter().any(|c| *c == ch)
+            any.contains(&ch)
         } else {
             false
         }
@@ -116,7 +118,7 @@ impl Grid {
     }
 
     fn flood_fill_cell(&mut self, x: isize, y: isize, source: char, replace: char) -> bool {
-        let mut q = vec![(x, y)];
+        let mut q = alloc::vec![(x, y)];
         let mut touch_border = false;
 
         while let Some((x, y)) = q.pop() {
@@ -154,12 +156,12 @@ impl Grid {
     fn with_inbetween_cells(&self) -> Self {
         let w = self.width * 2;
         let h = self.height * 2;
-        let mut rows = vec![vec![' '; w]; h];
+        let mut rows = alloc::vec![alloc::vec![' '; w]; h];
 
         for (x, y) in product_iter(w, h) {
             if (x % 2) == 0 && (y % 2) == 0 {
-                let gx = (x as f32 / 2.0).floor() as isize;
-                let gy = (y as f32 / 2.0).floor() as isize;
+                let gx = x / 2;
+                let gy = y / 2;
                 if let Some(ch) = self.get(gx, gy) {
                     rows[y as usize][x as usize] = ch;
                 }
@@ -177,13 +179,13 @@ impl Grid {
     fn shrink_grid(&self) -> Self {
         let w = self.width / 2;
         let h = self.height / 2;
-        let mut rows = vec![vec![' '; w]; h];
+        let mut rows = alloc::vec![alloc::vec![' '; w]; h];
         for (x, y) in product_iter(w, h) {
             // rows[y as usize][x as usize] = '#';
             // if (x % 2) == 0 && (y % 2) == 0 {
 
-            let gx = (x as f32 * 2.0).floor() as isize;
-            let gy = (y as f32 * 2.0).floor() as isize;
+            let gx = x * 2;
+            let gy = y * 2;
             if let Some(ch) = self.get(gx, gy) {
                 rows[y as usize][x as usize] = ch;
             }
@@ -201,7 +203,7 @@ impl Grid {
     fn find_connected(&self, tile: (isize, isize)) -> Vec<GridCoord> {
         let mut connected = Vec::new();
         let (x, y) = tile;
-        for (ox, oy) in NEIGHBORS.iter() {
+        for (ox, oy) in NEIGHBORS {
             // inverse offset:
             let offset = (
                 if *ox == 1 { -1 } else { isize::from(*ox == -1) },
@@ -222,43 +224,33 @@ impl Grid {
         connected
     }
 
-    // find max steps
-    fn traverse(&self) -> (usize, HashSet<(isize, isize)>) {
-        let start = self.find_cell('S').expect("No start tile found!");
-
-        // queue with all neighbors connecting to start (coordinate, number-of-steps)
-        let mut q = self
-            .find_connected(start)
+    /// Tiles connected through the pipe on the given tile, the start tile connects
+    /// to every neighbor pipe pointing into it.
+    fn pipe_neighbors(&self, tile: GridCoord) -> Vec<GridCoord> {
+        let Some(ch) = self.get(tile.0, tile.1) else {
+            return Vec::new();
+        };
+        if ch == 'S' {
+            return self.find_connected(tile);
+        }
+        PIPES
             .iter()
-            .map(|coord| (*coord, 1))
-            .collect::<VecDeque<_>>();
-        let mut visited = HashSet::new();
-        let mut max_steps = 0;
-
-        while let Some((current, steps)) = q.pop_back() {
-            if steps > max_steps {
-                max_steps = steps;
-            }
-            visited.insert(current);
-            let Some(ch) = self.get(current.0, current.1) else {
-                continue;
-            };
-            if let Some((_, (a, b))) = PIPES.iter().find(|(pipe, _)| *pipe == ch) {
+            .find(|(pipe, _)| *pipe == ch)
+            .map(|(_, (a, b))| {
                 // a and b are offsets indicating the pipe direction
                 // get the absolute coordinates:
-                let a = (current.0 + a.0, current.1 + a.1);
-                let b = (current.0 + b.0, current.1 + b.1);
+                alloc::vec![(tile.0 + a.0, tile.1 + a.1), (tile.0 + b.0, tile.1 + b.1)]
+            })
+            .unwrap_or_default()
+    }
 
-                if !visited.contains(&a) {
-                    q.push_front((a, steps + 1));
-                }
-                if !visited.contains(&b) {
-                    q.push_front((b, steps + 1));
-                }
-            }
-        }
+    // find max steps
+    fn traverse(&self) -> (usize, HashSet<(isize, isize)>) {
+        let start = self.find_cell('S').expect("No start tile found!");
+        let steps = graph::bfs(start, |tile| self.pipe_neighbors(*tile));
+        let max_steps = steps.values().copied().max().unwrap_or(0);
 
-        (max_steps, visited)
+        (max_steps, steps.into_keys().collect())
     }
 
     // any tiles that are not part of the main loop get replaced by ground tile
@@ -289,9 +281,10 @@ impl Debug for Grid {
     }
 }
 
-fn main() {
-    let contents = read_to_string("day10-input.txt").expect("Invalid Input!");
-    let grid = Grid::from_contents(&contents);
+/// Number of tiles enclosed by the main loop.
+#[must_use]
+pub fn count_enclosed_tiles(contents: &str) -> usize {
+    let grid = Grid::from_contents(contents);
     // doubles the size of the grid, filling space inbetween cells with space (' ')
     let mut grid = grid.with_inbetween_cells();
 
@@ -309,15 +302,14 @@ fn main() {
     let grid = grid.shrink_grid();
 
     // count all cells that are "I" cells
-    let num = grid.count_cells('I');
-
-    // this is the answer: (phew!!! that was hard, I think a graph may have been easier? i dunno)
-    println!("num: {}", num);
+    grid.count_cells('I')
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Grid;
+    use super::Grid;
+    #[cfg(feature = "std")]
+    use crate::snapshot::assert_debug_snapshot;
 
     const EXAMPLE_INPUT_5: &str = "
     .F----7F7F7F7F-7....
@@ -364,4 +356,18 @@ mod tests {
         let num = grid.count_cells('I');
         assert_eq!(num, 10);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_snapshots() {
+        let grid = Grid::from_contents(EXAMPLE_INPUT_5);
+        let mut grid = grid.with_inbetween_cells();
+        grid.set_connecting_cells();
+        assert_debug_snapshot("day10_set_connecting_cells", &grid);
+        grid.set_junk_pipes();
+        grid.flood_fill_cells();
+        assert_debug_snapshot("day10_flood_fill_cells", &grid);
+        let grid = grid.shrink_grid();
+        assert_debug_snapshot("day10_shrink_grid", &grid);
+    }
 }
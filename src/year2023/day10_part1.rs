@@ -0,0 +1,8 @@
+use adventofcode::{input::read_input, year2023::day10::part1::Map};
+
+fn main() {
+    let contents = read_input(2023, 10).expect("Invalid Input!");
+    let map = Map::from_contents(&contents);
+    let max_path = map.traverse();
+    println!("Solution: {max_path}");
+}
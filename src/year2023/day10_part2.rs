@@ -0,0 +1,9 @@
+use adventofcode::{input::read_input, year2023::day10::part2::count_enclosed_tiles};
+
+fn main() {
+    let contents = read_input(2023, 10).expect("Invalid Input!");
+    let num = count_enclosed_tiles(&contents);
+
+    // this is the answer: (phew!!! that was hard, I think a graph may have been easier? i dunno)
+    println!("num: {num}");
+}
@@ -0,0 +1,250 @@
+use alloc::vec::Vec;
+use core::{cmp::Ordering, fmt};
+
+use crate::collections::HashSet;
+#[cfg(feature = "std")]
+use crate::repl::{self, Repl};
+
+pub type Point = (i32, i32);
+
+pub fn distance(a: Point, b: Point) -> i32 {
+    (b.0 - a.0).abs() + (b.1 - a.1).abs()
+}
+
+const CELL_EMPTY: char = '.';
+
+const CELL_GALAXY: char = '#';
+
+#[derive(PartialEq)]
+pub struct Grid {
+    pub rows: Vec<Vec<char>>,
+}
+
+impl Grid {
+    pub fn from_contents(contents: &str) -> Self {
+        Self {
+            rows: contents
+                .trim()
+                .lines()
+                .map(|row| row.trim().chars().collect())
+                .collect(),
+        }
+    }
+
+    fn is_row_empty(&self, y: usize) -> bool {
+        self.rows[y].iter().all(|ch| *ch == CELL_EMPTY)
+    }
+
+    fn is_col_empty(&self, x: usize) -> bool {
+        self.rows.iter().all(|row| row[x] == CELL_EMPTY)
+    }
+
+    /// The universe with every empty row and column repeated `expansion` more times.
+    #[cfg(test)]
+    fn expand(&self, expansion: usize) -> Self {
+        let mut rows = Vec::new();
+        for (y, row) in self.rows.iter().enumerate() {
+            let mut expanded = Vec::new();
+            for (x, ch) in row.iter().enumerate() {
+                let n = if self.is_col_empty(x) {
+                    expansion + 1
+                } else {
+                    1
+                };
+                expanded.extend(core::iter::repeat_n(*ch, n));
+            }
+            let n = if self.is_row_empty(y) {
+                expansion + 1
+            } else {
+                1
+            };
+            rows.extend(core::iter::repeat_n(expanded, n));
+        }
+        Self { rows }
+    }
+
+    pub fn galaxies(&self, expansion: usize) -> Vec<Point> {
+        let mut galaxies = Vec::new();
+        let mut gx = 0;
+        let mut gy = 0;
+        self.rows.iter().enumerate().for_each(|(y, row)| {
+            if self.is_row_empty(y) {
+                gy += expansion;
+            } else {
+                gx = 0;
+                row.iter().enumerate().for_each(|(x, ch)| {
+                    if self.is_col_empty(x) {
+                        gx += expansion;
+                    } else if *ch == CELL_GALAXY {
+                        galaxies.push((i32::try_from(gx).unwrap(), i32::try_from(gy).unwrap()));
+                    }
+
+                    gx += 1;
+                });
+            }
+            gy += 1;
+        });
+        galaxies
+    }
+
+    pub fn all_pairs_of_galaxies(&self, expansion: usize) -> Vec<(Point, Point)> {
+        // collect all the coordinates of galaxies
+        let galaxies = self.galaxies(expansion);
+
+        // combine them together into all two possible pairs
+        // then collect into a set to deduplicate and finally return as a list
+        galaxies
+            .iter()
+            .flat_map(|a| {
+                galaxies.iter().filter_map(move |b| match a.cmp(b) {
+                    Ordering::Greater => Some((*a, *b)),
+                    Ordering::Less => Some((*b, *a)),
+                    Ordering::Equal => None,
+                })
+            })
+            .collect::<HashSet<_>>()
+            .iter()
+            .copied()
+            .collect::<Vec<_>>()
+    }
+
+    pub fn distances_between_galaxies(&self, expansion: usize) -> Vec<i32> {
+        self.all_pairs_of_galaxies(expansion)
+            .into_iter()
+            .map(|(a, b)| distance(a, b))
+            .collect()
+    }
+}
+
+impl fmt::Debug for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.rows {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+#[must_use]
+pub fn commands() -> Repl<'static, Grid> {
+    Repl::new("day11").command(
+        "distance",
+        "distance <galaxy> <galaxy> [--expansion <factor>]",
+        |grid: &Grid, args| {
+            let expansion = match args.iter().position(|arg| *arg == "--expansion") {
+                Some(index) => repl::arg::<usize>(args, index + 1, "factor")?,
+                None => 2,
+            };
+            if expansion == 0 {
+                return Err(anyhow::anyhow!("Invalid Expansion Factor!"));
+            }
+
+            // galaxies are numbered from 1 in reading order, like in the puzzle
+            let galaxies = grid.galaxies(expansion - 1);
+            let galaxy = |index: usize, name: &str| {
+                let number = repl::arg::<usize>(args, index, name)?;
+                number
+                    .checked_sub(1)
+                    .and_then(|number| galaxies.get(number))
+                    .copied()
+                    .ok_or(anyhow::anyhow!("Unknown Galaxy {number}!"))
+            };
+            Ok(distance(galaxy(0, "galaxy")?, galaxy(1, "galaxy")?).to_string())
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use super::commands;
+    use super::Grid;
+    #[cfg(feature = "std")]
+    use crate::snapshot::assert_debug_snapshot;
+
+    const EXAMPLE_INPUT: &str = "
+    ...#......
+    .......#..
+    #.........
+    ..........
+    ......#...
+    .#........
+    .........#
+    ..........
+    .......#..
+    #...#.....
+    ";
+
+    const EXAMPLE_INPUT_EXPANDED: &str = "
+    ....#........
+    .........#...
+    #............
+    .............
+    .............
+    ........#....
+    .#...........
+    ............#
+    .............
+    .............
+    .........#...
+    #....#.......
+    ";
+
+    #[test]
+    fn test_from_contents() {
+        let grid = Grid::from_contents(EXAMPLE_INPUT);
+        assert_eq!(
+            grid.galaxies(1),
+            Grid::from_contents(EXAMPLE_INPUT_EXPANDED).galaxies(0)
+        );
+        assert_eq!(grid.galaxies(1).len(), 9);
+    }
+
+    #[test]
+    fn test_expand() {
+        let grid = Grid::from_contents(EXAMPLE_INPUT);
+        let expanded = grid.expand(1);
+        assert_eq!(expanded, Grid::from_contents(EXAMPLE_INPUT_EXPANDED));
+        assert_eq!(expanded.galaxies(0), grid.galaxies(1));
+        #[cfg(feature = "std")]
+        assert_debug_snapshot("day11_expand", &expanded);
+    }
+
+    #[test]
+    fn test_all_pairs_of_galaxies() {
+        let grid = Grid::from_contents(EXAMPLE_INPUT);
+        assert_eq!(grid.all_pairs_of_galaxies(10).len(), 36);
+    }
+
+    #[test]
+    fn test_distances_between_galaxies() {
+        let grid = Grid::from_contents(EXAMPLE_INPUT);
+        assert_eq!(grid.distances_between_galaxies(1).iter().sum::<i32>(), 374);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_repl() {
+        let grid = Grid::from_contents(EXAMPLE_INPUT);
+        let repl = commands();
+        assert_eq!(repl.eval(&grid, "distance 5 9").unwrap().unwrap(), "9");
+        assert_eq!(repl.eval(&grid, "distance 1 7").unwrap().unwrap(), "15");
+        assert_eq!(repl.eval(&grid, "distance 3 6").unwrap().unwrap(), "17");
+        assert_eq!(
+            repl.eval(&grid, "distance 3 6 --expansion 10")
+                .unwrap()
+                .unwrap(),
+            "49"
+        );
+        assert!(repl.eval(&grid, "distance 0 1").unwrap().is_err());
+        assert!(repl.eval(&grid, "distance 1 10").unwrap().is_err());
+        assert!(repl
+            .eval(&grid, "distance 1 2 --expansion")
+            .unwrap()
+            .is_err());
+    }
+}
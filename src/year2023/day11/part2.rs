@@ -1,27 +1,25 @@
-#![warn(clippy::pedantic)]
-use std::{
-    cmp::Ordering,
-    collections::HashSet,
-    fmt::{self},
-    fs::read_to_string,
-};
+use alloc::vec::Vec;
+use core::{cmp::Ordering, fmt};
 
-type Point = (i64, i64);
+use crate::collections::HashSet;
 
-fn distance(a: Point, b: Point) -> i64 {
+pub type Point = (i64, i64);
+
+pub fn distance(a: Point, b: Point) -> i64 {
     (b.0 - a.0).abs() + (b.1 - a.1).abs()
 }
 
 const CELL_EMPTY: char = '.';
+
 const CELL_GALAXY: char = '#';
 
 #[derive(PartialEq)]
-struct Grid {
+pub struct Grid {
     pub rows: Vec<Vec<char>>,
 }
 
 impl Grid {
-    fn from_contents(contents: &str) -> Self {
+    pub fn from_contents(contents: &str) -> Self {
         Self {
             rows: contents
                 .trim()
@@ -39,7 +37,7 @@ impl Grid {
         self.rows.iter().all(|row| row[x] == CELL_EMPTY)
     }
 
-    fn galaxies(&self, expansion: usize) -> Vec<Point> {
+    pub fn galaxies(&self, expansion: usize) -> Vec<Point> {
         let mut galaxies = Vec::new();
         let mut gx = 0;
         let mut gy = 0;
@@ -63,7 +61,7 @@ impl Grid {
         galaxies
     }
 
-    fn all_pairs_of_galaxies(&self, expansion: usize) -> Vec<(Point, Point)> {
+    pub fn all_pairs_of_galaxies(&self, expansion: usize) -> Vec<(Point, Point)> {
         // collect all the coordinates of galaxies
         let galaxies = self.galaxies(expansion);
 
@@ -84,7 +82,7 @@ impl Grid {
             .collect::<Vec<_>>()
     }
 
-    fn distances_between_galaxies(&self, expansion: usize) -> Vec<i64> {
+    pub fn distances_between_galaxies(&self, expansion: usize) -> Vec<i64> {
         self.all_pairs_of_galaxies(expansion)
             .into_iter()
             .map(|(a, b)| distance(a, b))
@@ -104,18 +102,9 @@ impl fmt::Debug for Grid {
     }
 }
 
-fn main() {
-    let contents = read_to_string("day11-input.txt").expect("Invalid Input!");
-    let grid = Grid::from_contents(&contents);
-    println!(
-        "Solution: {}",
-        grid.distances_between_galaxies(1_000_000 - 1).iter().sum::<i64>()
-    );
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::Grid;
+    use super::Grid;
 
     const EXAMPLE_INPUT: &str = "
     ...#......
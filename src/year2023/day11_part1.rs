@@ -0,0 +1,22 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    input::{read_input, repl_requested},
+    year2023::day11::part1::{commands, Grid},
+};
+
+fn main() {
+    let contents = read_input(2023, 11).expect("Invalid Input!");
+    let grid = Grid::from_contents(&contents);
+
+    if repl_requested() {
+        commands()
+            .run(&grid, std::io::stdin().lock(), std::io::stdout())
+            .expect("REPL Error!");
+        return;
+    }
+
+    println!(
+        "Solution: {}",
+        grid.distances_between_galaxies(1).iter().sum::<i32>()
+    );
+}
@@ -0,0 +1,13 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{input::read_input, year2023::day11::part2::Grid};
+
+fn main() {
+    let contents = read_input(2023, 11).expect("Invalid Input!");
+    let grid = Grid::from_contents(&contents);
+    println!(
+        "Solution: {}",
+        grid.distances_between_galaxies(1_000_000 - 1)
+            .iter()
+            .sum::<i64>()
+    );
+}
@@ -1,8 +1,14 @@
-#![warn(clippy::pedantic)]
-use std::fs::read_to_string;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::checked::{Arithmetic, Overflow};
+
+pub const ARITHMETIC: Arithmetic = Arithmetic::new(12);
 
 // springs, groups
-fn parse_content(content: &str) -> Vec<(String, Vec<u64>)> {
+pub fn parse_content(content: &str) -> Vec<(String, Vec<u64>)> {
     content
         .trim()
         .lines()
@@ -38,12 +44,12 @@ fn valid(chars: &[char], numbers: &[u64]) -> bool {
     acc == numbers
 }
 
-fn combinations(springs: &str, groups: &[u64]) -> u64 {
+pub fn combinations(springs: &str, groups: &[u64]) -> Result<u64, Overflow> {
     let indices = springs
         .char_indices()
         .filter_map(|(i, ch)| if ch == '?' { Some(i) } else { None })
         .collect::<Vec<usize>>();
-    let n_total = 1 << indices.len(); // 2^n
+    let n_total = ARITHMETIC.pow("2^unknown springs", 2u64, indices.len())?;
     let mut n_valid = 0;
 
     for n in 0..n_total {
@@ -61,22 +67,12 @@ fn combinations(springs: &str, groups: &[u64]) -> u64 {
         }
     }
 
-    n_valid
-}
-
-fn main() {
-    let contents = read_to_string("day12-input.txt").expect("Invalid Input!");
-    let rows = parse_content(&contents);
-    let n_valids = rows
-        .iter()
-        .map(|(chars, numbers)| combinations(chars, numbers))
-        .sum::<u64>();
-    println!("Solution: {n_valids}");
+    Ok(n_valid)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{combinations, parse_content};
+    use super::{combinations, parse_content};
 
     const EXAMPLE_INPUT: &str = "
     ???.### 1,1,3
@@ -92,7 +88,7 @@ mod tests {
         let rows = parse_content(EXAMPLE_INPUT);
         let valids = rows
             .iter()
-            .map(|(chars, numbers)| combinations(chars, numbers))
+            .map(|(chars, numbers)| combinations(chars, numbers).unwrap())
             .collect::<Vec<u64>>();
         assert_eq!(valids, &[1, 4, 1, 1, 4, 10]);
         assert_eq!(valids.iter().sum::<u64>(), 21);
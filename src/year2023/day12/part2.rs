@@ -1,8 +1,19 @@
-#![warn(clippy::pedantic)]
-use std::{collections::HashMap, fs::read_to_string};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    answer::Answer,
+    checked::{Arithmetic, Overflow},
+    collections::HashMap,
+};
+
+const ARITHMETIC: Arithmetic = Arithmetic::new(12);
 
 // springs, groups
-fn parse_content(content: &str, n_repititions: usize) -> Vec<(String, Vec<u64>)> {
+pub fn parse_content(content: &str, n_repititions: usize) -> Vec<(String, Vec<u64>)> {
     content
         .trim()
         .lines()
@@ -15,7 +26,7 @@ fn parse_content(content: &str, n_repititions: usize) -> Vec<(String, Vec<u64>)>
                 .map(Result::unwrap)
                 .collect::<Vec<u64>>();
 
-            let mut springs_ = vec![springs.clone()];
+            let mut springs_ = alloc::vec![springs.clone()];
             let mut groups_ = groups.clone();
             if n_repititions > 0 {
                 for _ in 0..n_repititions {
@@ -29,72 +40,66 @@ fn parse_content(content: &str, n_repititions: usize) -> Vec<(String, Vec<u64>)>
         .collect()
 }
 
-fn combinations(springs: &str, groups: &[u64]) -> u64 {
+/// Arrangements of the springs matching the groups, counted as answer as they explode with
+/// more repetitions.
+pub fn combinations(springs: &str, groups: &[u64]) -> Result<Answer, Overflow> {
     fn fun(
         springs: &str,
         remaining_groups: &[u64],
         current_group: u64,
-        cache: &mut HashMap<String, u64>,
-    ) -> u64 {
+        cache: &mut HashMap<String, Answer>,
+    ) -> Result<Answer, Overflow> {
         let key = format!("{springs} {remaining_groups:?} {current_group:?}");
-        if let Some(&count) = cache.get(&key) {
-            return count;
+        if let Some(count) = cache.get(&key) {
+            return Ok(count.clone());
         }
 
         if springs.is_empty() {
             if (current_group == 0 && remaining_groups.is_empty())
                 || (remaining_groups.len() == 1 && current_group == remaining_groups[0])
             {
-                cache.insert(key, 1);
-                return 1;
+                cache.insert(key, Answer::from(1u8));
+                return Ok(Answer::from(1u8));
             }
-            cache.insert(key, 0);
-            return 0;
+            cache.insert(key, Answer::from(0u8));
+            return Ok(Answer::from(0u8));
         }
 
         if (!remaining_groups.is_empty() && current_group > remaining_groups[0])
             || (remaining_groups.is_empty() && current_group > 0)
         {
-            cache.insert(key, 0);
-            return 0;
+            cache.insert(key, Answer::from(0u8));
+            return Ok(Answer::from(0u8));
         }
 
         let ch: char = springs.chars().next().unwrap();
-        let mut n: u64 = 0;
+        let mut n = Answer::from(0u8);
 
         if ch == '#' || ch == '?' {
-            n += fun(&springs[1..], remaining_groups, current_group + 1, cache);
+            let current_group = ARITHMETIC.add("damaged springs of the group", current_group, 1)?;
+            n += fun(&springs[1..], remaining_groups, current_group, cache)?;
         }
 
         if ch == '.' || ch == '?' {
             if !remaining_groups.is_empty() && current_group == remaining_groups[0] {
-                n += fun(&springs[1..], &remaining_groups[1..], 0, cache);
+                n += fun(&springs[1..], &remaining_groups[1..], 0, cache)?;
             } else if current_group == 0 {
-                n += fun(&springs[1..], remaining_groups, 0, cache);
+                n += fun(&springs[1..], remaining_groups, 0, cache)?;
             }
         }
 
-        cache.insert(key, n);
-        n
+        cache.insert(key, n.clone());
+        Ok(n)
     }
 
     let mut cache = HashMap::new();
     fun(springs, groups, 0, &mut cache)
 }
 
-fn main() {
-    let contents = read_to_string("day12-input.txt").expect("Invalid Input!");
-    let rows = parse_content(&contents, 4);
-        let valids = rows
-            .iter()
-            .map(|(springs, groups)| combinations(springs, groups))
-            .sum::<u64>();
-        println!("Solution: {valids}");
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::{combinations, parse_content};
+    use super::{combinations, parse_content};
+    use crate::answer::Answer;
 
     const EXAMPLE_INPUT: &str = "
     ???.### 1,1,3
@@ -110,17 +115,20 @@ mod tests {
         let lines = parse_content(EXAMPLE_INPUT, 0);
         let valids = lines
             .iter()
-            .map(|(springs, groups)| combinations(springs, groups))
-            .collect::<Vec<u64>>();
-        assert_eq!(valids, &[1, 4, 1, 1, 4, 10]);
-        assert_eq!(valids.iter().sum::<u64>(), 21);
+            .map(|(springs, groups)| combinations(springs, groups).unwrap())
+            .collect::<Vec<Answer>>();
+        assert_eq!(valids, [1u64, 4, 1, 1, 4, 10].map(Answer::from));
+        assert_eq!(valids.into_iter().sum::<Answer>(), Answer::from(21u8));
 
         let lines = parse_content(EXAMPLE_INPUT, 4);
         let valids = lines
             .iter()
-            .map(|(springs, groups)| combinations(springs, groups))
-            .collect::<Vec<u64>>();
-        assert_eq!(valids, &[1, 16384, 1, 16, 2500, 506_250]);
-        assert_eq!(valids.iter().sum::<u64>(), 525_152);
+            .map(|(springs, groups)| combinations(springs, groups).unwrap())
+            .collect::<Vec<Answer>>();
+        assert_eq!(
+            valids,
+            [1u64, 16384, 1, 16, 2500, 506_250].map(Answer::from)
+        );
+        assert_eq!(valids.into_iter().sum::<Answer>(), Answer::from(525_152u32));
     }
 }
@@ -0,0 +1,15 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    input::read_input,
+    year2023::day12::part1::{combinations, parse_content, ARITHMETIC},
+};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 12)?;
+    let rows = parse_content(&contents);
+    let n_valids = rows.iter().try_fold(0, |sum, (chars, numbers)| {
+        ARITHMETIC.add("sum of arrangements", sum, combinations(chars, numbers)?)
+    })?;
+    println!("Solution: {n_valids}");
+    Ok(())
+}
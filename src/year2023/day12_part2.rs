@@ -0,0 +1,17 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    answer::Answer,
+    input::read_input,
+    year2023::day12::part2::{combinations, parse_content},
+};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 12)?;
+    let rows = parse_content(&contents, 4);
+    let valids = rows
+        .iter()
+        .map(|(springs, groups)| combinations(springs, groups))
+        .sum::<Result<Answer, _>>()?;
+    println!("Solution: {valids}");
+    Ok(())
+}
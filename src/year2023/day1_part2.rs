@@ -0,0 +1,173 @@
+use std::fmt::Write;
+
+use adventofcode::{
+    calibration::{Calibration, CHUNK_SIZE},
+    input::{flag, open_input, option, read_input},
+    vocabulary::{Match, OverlapMode, Vocabulary, ENGLISH},
+    year2023::day1::part2::{sum_calibration_values, Extraction, NumberIter},
+};
+
+/// ANSI colors of the first and last number in the explanation, and of the characters they
+/// share.
+const FIRST_COLOR: &str = "\x1b[1;32m";
+const LAST_COLOR: &str = "\x1b[1;34m";
+const SHARED_COLOR: &str = "\x1b[1;36m";
+const RESET: &str = "\x1b[0m";
+
+/// The line with the first number highlighted in green, the last one in blue and the
+/// characters they share in cyan, followed by the calibration value of the extraction and
+/// the two numbers.
+fn explain(line: &str, vocabulary: &Vocabulary, extraction: Extraction) -> String {
+    let Some(first) = NumberIter::with_vocabulary(line, vocabulary)
+        .matches()
+        .next()
+    else {
+        return format!("{line} => no digit");
+    };
+    let last = vocabulary.last_match(line).unwrap_or(first);
+    let contains = |found: Match, offset: usize| (found.start..found.end).contains(&offset);
+
+    let mut text = String::new();
+    let mut color = "";
+    for (offset, character) in line.char_indices() {
+        let next_color = match (contains(first, offset), contains(last, offset)) {
+            (true, true) => SHARED_COLOR,
+            (true, false) => FIRST_COLOR,
+            (false, true) => LAST_COLOR,
+            (false, false) => "",
+        };
+        if next_color != color {
+            if !color.is_empty() {
+                text.push_str(RESET);
+            }
+            text.push_str(next_color);
+            color = next_color;
+        }
+        text.push(character);
+    }
+    if !color.is_empty() {
+        text.push_str(RESET);
+    }
+
+    let _ = match extraction.extract(line, vocabulary) {
+        Ok(value) => write!(text, " => {value}"),
+        Err(err) => write!(text, " => {err}"),
+    };
+    let _ = write!(
+        text,
+        " ({} {}, {} {})",
+        first.kind,
+        &line[first.start..first.end],
+        last.kind,
+        &line[last.start..last.end]
+    );
+    text
+}
+
+#[test]
+fn test_explain() {
+    let vocabulary = Vocabulary::default();
+    assert_eq!(
+        explain("a1twob", &vocabulary, Extraction::default()),
+        "a\x1b[1;32m1\x1b[0m\x1b[1;34mtwo\x1b[0mb => 12 (digit 1, word two)"
+    );
+    assert_eq!(
+        explain("eightwo", &vocabulary, Extraction::default()),
+        "\x1b[1;32meigh\x1b[0m\x1b[1;36mt\x1b[0m\x1b[1;34mwo\x1b[0m => 82 (word eight, word two)"
+    );
+    assert_eq!(
+        explain("x٧", &vocabulary, Extraction::default()),
+        "x\x1b[1;36m٧\x1b[0m => 77 (digit ٧, digit ٧)"
+    );
+    assert_eq!(
+        explain("a1twob3", &vocabulary, Extraction::All),
+        "a\x1b[1;32m1\x1b[0mtwob\x1b[1;34m3\x1b[0m => 123 (digit 1, digit 3)"
+    );
+    assert_eq!(
+        explain("xyz", &vocabulary, Extraction::default()),
+        "xyz => no digit"
+    );
+}
+
+/// The newly-improved calibration document consists of lines of text;
+/// each line originally contained a specific calibration value that the
+/// Elves now need to recover. On each line, the calibration value
+/// can be found by combining the first digit and the last digit
+/// (in that order) to form a single two-digit number.
+///
+/// For example:
+///
+/// 1abc2
+/// pqr3stu8vwx
+/// a1b2c3d4e5f
+/// treb7uchet
+/// In this example, the calibration values of these four lines are
+/// 12, 38, 15, and 77. Adding these together produces 142.
+///
+/// Consider your entire calibration document. What is the sum of all
+/// of the calibration values?
+///
+/// --- Part Two ---
+/// Your calculation isn't quite right. It looks like some of the digits
+/// are actually spelled out with letters: one, two, three, four, five,
+/// six, seven, eight, and nine also count as valid "digits".
+///
+/// Equipped with this new information, you now need to find the real
+/// first and last digit on each line. For example:
+///
+/// two1nine
+/// eightwothree
+/// abcone2threexyz
+/// xtwone3four
+/// 4nineeightseven2
+/// zoneight234
+/// 7pqrstsixteen
+/// In this example, the calibration values are 29, 83, 13, 24, 42,
+/// 14, and 76. Adding these together produces 281.
+///
+/// How overlapping words like `eightwo` count is chosen with `--overlap=<mode>`,
+/// `overlapping` (the default), `leftmost-longest` or `leftmost-first`. With `--explain`
+/// every line is printed with the numbers its calibration value is made of highlighted.
+/// `--extract=<extraction>` makes the calibration value of other numbers of the line, see
+/// [`Extraction`].
+fn main() -> anyhow::Result<()> {
+    let overlap = option("overlap")
+        .map(|overlap| overlap.parse::<OverlapMode>())
+        .transpose()
+        .map_err(anyhow::Error::msg)?
+        .unwrap_or_default();
+    let vocabulary = Vocabulary::default().overlap(overlap);
+    let extraction = option("extract")
+        .map(|extraction| extraction.parse::<Extraction>())
+        .transpose()
+        .map_err(anyhow::Error::msg)?
+        .unwrap_or_default();
+    let explain_lines = flag("explain");
+
+    // the puzzle's own calibration values are summed on bytes, streaming the input
+    let calibration = if overlap == OverlapMode::default()
+        && extraction == Extraction::default()
+        && !explain_lines
+    {
+        Calibration::new(&ENGLISH[1..])
+    } else {
+        None
+    };
+    if let Some(calibration) = calibration {
+        let number = calibration.sum_reader(open_input(2023, 1)?, CHUNK_SIZE)?;
+        println!("Sum: {}", number);
+        return Ok(());
+    }
+
+    let contents = read_input(2023, 1)?;
+    if explain_lines {
+        for line in contents.split_terminator('\n') {
+            println!("{}", explain(line, &vocabulary, extraction));
+        }
+    }
+
+    let number = sum_calibration_values(&contents, &vocabulary, extraction)?;
+
+    println!("Sum: {}", number);
+    Ok(())
+}
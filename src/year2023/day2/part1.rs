@@ -0,0 +1,23 @@
+use core::hash::BuildHasher;
+
+use crate::{
+    collections::HashMap,
+    cubes::{ColorId, Game},
+};
+
+/// Bag of the puzzle, overridden with `--bag=<draw>` or replaced by a `--where=<query>`.
+pub const BAG: &str = "12 red, 13 green, 14 blue";
+
+/// Games possible with at most the count of each color in the filter.
+pub fn filter_games_by_min_count<'a, S: BuildHasher>(
+    games: &'a [Game],
+    filter: &'a HashMap<ColorId, usize, S>,
+) -> impl Iterator<Item = &'a Game> {
+    games.iter().filter(|game| {
+        game.draws.iter().all(|draw| {
+            draw.cubes()
+                .iter()
+                .all(|(color, count)| filter.get(color).copied().unwrap_or(0) >= *count)
+        })
+    })
+}
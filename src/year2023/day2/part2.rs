@@ -0,0 +1,29 @@
+use crate::{
+    collections::HashMap,
+    cubes::{ColorId, Game},
+};
+
+/// Most cubes of each color drawn at once in the game.
+#[must_use]
+pub fn get_max_count_for_game(game: &Game) -> HashMap<ColorId, usize> {
+    let Game { draws, .. } = game;
+    draws.iter().fold(HashMap::new(), |mut max, draw| {
+        for (color, count) in draw.cubes() {
+            if count > max.entry(*color).or_insert(0) {
+                max.insert(*color, *count);
+            }
+        }
+        max
+    })
+}
+
+/// Sum of the powers of the minimal bags of the games.
+#[must_use]
+pub fn sum_of_powers(games: &[Game]) -> usize {
+    games
+        .iter()
+        .map(get_max_count_for_game)
+        // a game without cubes has an empty minimal bag with a power of 0
+        .map(|max| max.values().copied().reduce(|a, b| a * b).unwrap_or(0))
+        .sum()
+}
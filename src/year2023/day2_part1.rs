@@ -0,0 +1,57 @@
+use adventofcode::{
+    answer::Answer,
+    collections::HashMap,
+    cubes::{normalize, parse_draw, parse_games_from_contents},
+    game_query::{Metric, Query},
+    input::{flag, option, read_input},
+    year2023::day2::part1::{filter_games_by_min_count, BAG},
+};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 2)?;
+    if flag("normalize") {
+        print!("{}", normalize(&contents)?);
+        return Ok(());
+    }
+    let (mut colors, games) = parse_games_from_contents(&contents)?;
+
+    // a query replaces the bag
+    let filter;
+    let matching = if let Some(query) = option("where") {
+        let query = Query::parse(&query, &colors)?;
+        let mut matching = Vec::new();
+        for game in &games {
+            if query.matches(game)? {
+                matching.push(game);
+            }
+        }
+        let ids = matching.iter().map(|game| game.id.to_string()).collect::<Vec<_>>();
+        println!("Games: {}", ids.join(", "));
+        matching
+    } else {
+        let bag = option("bag").unwrap_or_else(|| BAG.to_string());
+        let bag = parse_draw(&bag, &mut colors)?;
+        filter = bag.cubes().iter().copied().collect::<HashMap<_, _>>();
+        filter_games_by_min_count(&games, &filter).collect()
+    };
+
+    if let Some(metric) = option("sum") {
+        let values = Metric::parse(&metric, &colors)?;
+        let sum = matching
+            .iter()
+            .map(|game| values.eval(game).map(Answer::from))
+            .sum::<anyhow::Result<Answer>>()?;
+        println!("Sum of {metric}: {sum}");
+    } else if let Some(metric) = option("product") {
+        let values = Metric::parse(&metric, &colors)?;
+        let mut product = Answer::from(1u8);
+        for game in &matching {
+            product = product * Answer::from(values.eval(game)?);
+        }
+        println!("Product of {metric}: {product}");
+    } else {
+        let sum_of_ids: usize = matching.iter().map(|game| game.id).sum();
+        println!("Sum of IDs: {}", sum_of_ids);
+    }
+    Ok(())
+}
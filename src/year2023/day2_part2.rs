@@ -0,0 +1,67 @@
+use std::slice;
+
+use adventofcode::{
+    cubes::{normalize, parse_games_from_contents, Colors, Game},
+    inference::{estimate, Bag, FeasibleBags},
+    input::{flag, option, read_input},
+    year2023::day2::part2::sum_of_powers,
+};
+
+/// The bag like a draw, `7 blue, 4 red, 3 green`.
+fn format_bag(bag: &Bag, colors: &Colors) -> String {
+    colors
+        .iter()
+        .map(|(color, name)| format!("{} {name}", bag.count(color)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Prints the feasible bags of the total and the most likely ones, for the games.
+fn print_inference(name: &str, games: &[Game], colors: &Colors, total: usize, feasible: bool) {
+    if feasible {
+        for bag in FeasibleBags::new(games, colors.len(), total) {
+            println!("{name}: feasible {}", format_bag(&bag, colors));
+        }
+    }
+    match estimate(games, colors.len(), total) {
+        Some(estimate) => {
+            let bags = estimate
+                .bags
+                .iter()
+                .map(|bag| format_bag(bag, colors))
+                .collect::<Vec<_>>();
+            println!(
+                "{name}: {} feasible, most likely {} (likelihood {:e})",
+                estimate.feasible,
+                bags.join(" or "),
+                estimate.likelihood.to_f64()
+            );
+        }
+        None => println!("{name}: no feasible bag of {total} cubes"),
+    }
+}
+
+fn main() {
+    let contents = read_input(2023, 2).expect("Invalid Input!");
+    if flag("normalize") {
+        print!("{}", normalize(&contents).expect("Invalid Input!"));
+        return;
+    }
+    let (colors, games) = parse_games_from_contents(&contents).expect("Invalid Input!");
+
+    // bags of a total number of cubes inferred from the draws, per game and for all games
+    if let Some(total) = option("total") {
+        let total = total.parse::<usize>().expect("Invalid Total!");
+        let feasible = flag("feasible");
+        for game in &games {
+            let name = format!("Game {}", game.id);
+            print_inference(&name, slice::from_ref(game), &colors, total, feasible);
+        }
+        print_inference("All games", &games, &colors, total, feasible);
+        return;
+    }
+
+    let sum_of_powers = sum_of_powers(&games);
+
+    println!("powers: {:?}", sum_of_powers);
+}
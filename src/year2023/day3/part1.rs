@@ -1,7 +1,7 @@
-use std::fs::read_to_string;
+use alloc::{string::String, vec::Vec};
 
 #[derive(Debug)]
-struct Schematic {
+pub struct Schematic {
     data: Vec<Vec<char>>,
     width: usize,
     height: usize,
@@ -12,7 +12,10 @@ impl Schematic {
         if i < 0 || j < 0 || i > self.width as isize || j > self.height as isize {
             '.'
         } else {
-            self.data.get(i as usize).and_then(|row| row.get(j as usize).copied()).unwrap_or('.')
+            self.data
+                .get(i as usize)
+                .and_then(|row| row.get(j as usize).copied())
+                .unwrap_or('.')
         }
     }
 
@@ -22,7 +25,7 @@ impl Schematic {
     }
 }
 
-fn parse_schematic(contents: &str) -> anyhow::Result<Schematic> {
+pub fn parse_schematic(contents: &str) -> anyhow::Result<Schematic> {
     let data = contents
         .trim()
         .split_terminator('\n')
@@ -67,10 +70,11 @@ fn adjacents(i: usize, j: usize) -> impl Iterator<Item = (isize, isize)> {
         // bottom
         (0, 1),
     ];
-    ADJ.iter().map(move |(oi, oj)| (i as isize + oi, j as isize + oj))
+    ADJ.iter()
+        .map(move |(oi, oj)| (i as isize + oi, j as isize + oj))
 }
 
-fn find_part_numbers(schematic: &Schematic) -> Vec<usize> {
+pub fn find_part_numbers(schematic: &Schematic) -> Vec<usize> {
     let mut numbers = Vec::new();
 
     let mut current = String::new();
@@ -78,7 +82,7 @@ fn find_part_numbers(schematic: &Schematic) -> Vec<usize> {
     for (i, j) in product_range(schematic.height, schematic.width) {
         let char = schematic.get(i as isize, j as isize);
         let is_number = char.is_ascii_digit();
-        
+
         if is_number {
             current.push(char);
         } else {
@@ -99,18 +103,9 @@ fn find_part_numbers(schematic: &Schematic) -> Vec<usize> {
     numbers
 }
 
-fn main() {
-    let contents = read_to_string("day3-input.txt").expect("Invalid Input!");
-    let schematic = parse_schematic(&contents).unwrap();
-    let part_numbers = find_part_numbers(&schematic);
-
-    println!("Sum of Part Numbers: {:?}", part_numbers.iter().sum::<usize>());
-}
-
-
 #[cfg(test)]
 mod test {
-    use crate::{parse_schematic, find_part_numbers};
+    use super::{find_part_numbers, parse_schematic};
 
     const TEST_SCHEMATIC: &str = "
     467..114..
@@ -139,4 +134,4 @@ mod test {
         let part_numbers = find_part_numbers(&schematic);
         assert_eq!(part_numbers, &[467, 35, 633, 617, 592, 755, 664, 598]);
     }
-}
\ No newline at end of file
+}
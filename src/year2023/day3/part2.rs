@@ -1,7 +1,9 @@
-use std::{collections::HashMap, fs::read_to_string};
+use alloc::{string::String, vec::Vec};
+
+use crate::collections::HashMap;
 
 #[derive(Debug)]
-struct Schematic {
+pub struct Schematic {
     data: Vec<Vec<char>>,
     width: usize,
     height: usize,
@@ -24,7 +26,7 @@ impl Schematic {
     }
 }
 
-fn parse_schematic(contents: &str) -> anyhow::Result<Schematic> {
+pub fn parse_schematic(contents: &str) -> anyhow::Result<Schematic> {
     let data = contents
         .trim()
         .split_terminator('\n')
@@ -73,7 +75,7 @@ fn adjacents(i: usize, j: usize) -> impl Iterator<Item = (isize, isize)> {
         .map(move |(oi, oj)| (i as isize + oi, j as isize + oj))
 }
 
-fn find_gear_ratios(schematic: &Schematic) -> Vec<usize> {
+pub fn find_gear_ratios(schematic: &Schematic) -> Vec<usize> {
     const GEAR_SYMBOL: char = '*';
 
     // find gears with their marker symbol location:
@@ -129,20 +131,9 @@ fn find_gear_ratios(schematic: &Schematic) -> Vec<usize> {
         .collect()
 }
 
-fn main() {
-    let contents = read_to_string("day3-input.txt").expect("Invalid Input!");
-    let schematic = parse_schematic(&contents).unwrap();
-    let gear_ratios = find_gear_ratios(&schematic);
-
-    println!(
-        "Sum of Gear Ratios: {:?}",
-        gear_ratios.iter().sum::<usize>()
-    );
-}
-
 #[cfg(test)]
 mod test {
-    use crate::{find_gear_ratios, parse_schematic};
+    use super::{find_gear_ratios, parse_schematic};
 
     const TEST_SCHEMATIC: &str = "
     467..114..
@@ -169,7 +160,7 @@ mod test {
     fn test_find_gear_ratios() {
         let schematic = parse_schematic(TEST_SCHEMATIC).unwrap();
         let mut gear_ratios = find_gear_ratios(&schematic);
-        gear_ratios.sort();
-        assert_eq!(gear_ratios, &[16345, 451490]);
+        gear_ratios.sort_unstable();
+        assert_eq!(gear_ratios, &[16345, 451_490]);
     }
 }
@@ -0,0 +1,12 @@
+use adventofcode::{
+    input::read_input,
+    year2023::day3::part1::{find_part_numbers, parse_schematic},
+};
+
+fn main() {
+    let contents = read_input(2023, 3).expect("Invalid Input!");
+    let schematic = parse_schematic(&contents).unwrap();
+    let part_numbers = find_part_numbers(&schematic);
+
+    println!("Sum of Part Numbers: {:?}", part_numbers.iter().sum::<usize>());
+}
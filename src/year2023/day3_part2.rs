@@ -0,0 +1,15 @@
+use adventofcode::{
+    input::read_input,
+    year2023::day3::part2::{find_gear_ratios, parse_schematic},
+};
+
+fn main() {
+    let contents = read_input(2023, 3).expect("Invalid Input!");
+    let schematic = parse_schematic(&contents).unwrap();
+    let gear_ratios = find_gear_ratios(&schematic);
+
+    println!(
+        "Sum of Gear Ratios: {:?}",
+        gear_ratios.iter().sum::<usize>()
+    );
+}
@@ -1,13 +1,12 @@
-use std::{collections::HashSet, fs::read_to_string};
+use alloc::vec::Vec;
 
-fn parse_numbers(numbers: &str) -> Vec<u8> {
-    numbers
-        .split(' ')
-        .flat_map(str::parse::<u8>)
-        .collect()
+use crate::collections::HashSet;
+
+pub fn parse_numbers(numbers: &str) -> Vec<u8> {
+    numbers.split(' ').flat_map(str::parse::<u8>).collect()
 }
 
-fn parse_input(contents: &str) -> Vec<(Vec<u8>, Vec<u8>)> {
+pub fn parse_input(contents: &str) -> Vec<(Vec<u8>, Vec<u8>)> {
     contents
         .trim()
         .lines()
@@ -19,7 +18,7 @@ fn parse_input(contents: &str) -> Vec<(Vec<u8>, Vec<u8>)> {
         .collect()
 }
 
-fn find_winning_numbers(cards: &[(Vec<u8>, Vec<u8>)]) -> Vec<Vec<u8>> {
+pub fn find_winning_numbers(cards: &[(Vec<u8>, Vec<u8>)]) -> Vec<Vec<u8>> {
     cards
         .iter()
         .map(|(winning, numbers)| {
@@ -33,30 +32,23 @@ fn find_winning_numbers(cards: &[(Vec<u8>, Vec<u8>)]) -> Vec<Vec<u8>> {
         .collect()
 }
 
-fn calculate_score(winning: &[Vec<u8>]) -> u32 {
-        winning.iter().map(|winners| {
+pub fn calculate_score(winning: &[Vec<u8>]) -> u32 {
+    winning
+        .iter()
+        .map(|winners| {
             let score = winners.len() as i32;
             if score > 0 {
                 2_i32.pow((score - 1) as u32) as u32
             } else {
                 0
             }
-        }).sum()
-
-}
-
-fn main() {
-    let contents = read_to_string("day4-input.txt").expect("Invalid Input!");
-    let cards = parse_input(&contents);
-    let winning = find_winning_numbers(&cards);
-    let total: u32 = calculate_score(&winning);
-
-    println!("Total: {}", total);
+        })
+        .sum()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{find_winning_numbers, parse_input, calculate_score};
+    use super::{calculate_score, find_winning_numbers, parse_input};
 
     const EXAMPLE_INPUT: &str = "
     Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
@@ -99,7 +91,9 @@ mod tests {
     fn test_find_winning_numbers() {
         let cards = parse_input(EXAMPLE_INPUT);
         let mut winning = find_winning_numbers(&cards);
-        winning.iter_mut().for_each(|numbers| numbers.sort());
+        for numbers in &mut winning {
+            numbers.sort_unstable();
+        }
         assert_eq!(
             winning,
             vec![
@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+
+use crate::{answer::Answer, collections::HashSet};
+
+pub fn parse_numbers(numbers: &str) -> Vec<u8> {
+    numbers.split(' ').flat_map(str::parse::<u8>).collect()
+}
+
+pub fn parse_input(contents: &str) -> Vec<(Vec<u8>, Vec<u8>)> {
+    contents
+        .trim()
+        .lines()
+        .map(|line| {
+            let lindex = line.find(':').expect("Invalid Input!");
+            let (winning, numbers) = line[lindex + 1..].split_once('|').expect("Invalid Input!");
+            (parse_numbers(winning), parse_numbers(numbers))
+        })
+        .collect()
+}
+
+/// For each card return the index/number of the card and the number of winning numbers.
+pub fn find_winning_numbers(cards: &[(Vec<u8>, Vec<u8>)]) -> Vec<(usize, usize)> {
+    cards
+        .iter()
+        .enumerate()
+        .map(|(index, (winning, numbers))| {
+            let winning: HashSet<&u8> = HashSet::from_iter(winning);
+            let numbers: HashSet<&u8> = HashSet::from_iter(numbers);
+            (index, winning.intersection(&numbers).count())
+        })
+        .collect()
+}
+
+/// Total number of cards, every copy of a card wins one copy of each of the next <score>
+/// cards. The copies grow exponentially, so they are counted as answers.
+pub fn calculate_won_cards(scores: &[(usize, usize)]) -> Answer {
+    let mut copies = alloc::vec![Answer::from(1u8); scores.len()];
+    for &(index, score) in scores {
+        let won = copies[index].clone();
+        for copy in copies.iter_mut().skip(index + 1).take(score) {
+            *copy += won.clone();
+        }
+    }
+    copies.into_iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculate_won_cards, find_winning_numbers, parse_input};
+    use crate::answer::Answer;
+
+    const EXAMPLE_INPUT: &str = "
+    Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+    Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+    Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+    Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+    Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+    ";
+
+    #[test]
+    fn test_parse_input() {
+        let cards = parse_input(EXAMPLE_INPUT);
+        assert_eq!(
+            cards,
+            alloc::vec![
+                (alloc::vec![41, 48, 83, 86, 17], alloc::vec![83, 86, 6, 31, 17, 9, 48, 53]),
+                (
+                    alloc::vec![13, 32, 20, 16, 61],
+                    alloc::vec![61, 30, 68, 82, 17, 32, 24, 19]
+                ),
+                (alloc::vec![1, 21, 53, 59, 44], alloc::vec![69, 82, 63, 72, 16, 21, 14, 1]),
+                (
+                    alloc::vec![41, 92, 73, 84, 69],
+                    alloc::vec![59, 84, 76, 51, 58, 5, 54, 83]
+                ),
+                (
+                    alloc::vec![87, 83, 26, 28, 32],
+                    alloc::vec![88, 30, 70, 12, 93, 22, 82, 36]
+                ),
+                (
+                    alloc::vec![31, 18, 13, 56, 72],
+                    alloc::vec![74, 77, 10, 23, 35, 67, 36, 11]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_winning_numbers() {
+        let cards = parse_input(EXAMPLE_INPUT);
+        let winning = find_winning_numbers(&cards);
+        assert_eq!(
+            winning,
+            alloc::vec![(0, 4), (1, 2), (2, 2), (3, 1), (4, 0), (5, 0),]
+        );
+    }
+
+    #[test]
+    fn test_calculate_won_cards() {
+        let cards = parse_input(EXAMPLE_INPUT);
+        let winning = find_winning_numbers(&cards);
+        let num = calculate_won_cards(&winning);
+        assert_eq!(num, Answer::from(30u8));
+    }
+}
@@ -0,0 +1,13 @@
+use adventofcode::{
+    input::read_input,
+    year2023::day4::part1::{calculate_score, find_winning_numbers, parse_input},
+};
+
+fn main() {
+    let contents = read_input(2023, 4).expect("Invalid Input!");
+    let cards = parse_input(&contents);
+    let winning = find_winning_numbers(&cards);
+    let total: u32 = calculate_score(&winning);
+
+    println!("Total: {}", total);
+}
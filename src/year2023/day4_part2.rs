@@ -0,0 +1,12 @@
+use adventofcode::{
+    input::read_input,
+    year2023::day4::part2::{calculate_won_cards, find_winning_numbers, parse_input},
+};
+
+fn main() {
+    let contents = read_input(2023, 4).expect("Invalid Input!");
+    let cards = parse_input(&contents);
+    let winning = find_winning_numbers(&cards);
+    let num = calculate_won_cards(&winning);
+    println!("Total Cards: {}", num);
+}
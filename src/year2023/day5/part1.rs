@@ -1,8 +1,13 @@
-#![warn(clippy::pedantic)]
-use std::{collections::HashMap, fs::read_to_string, ops::Range};
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::repl::{self, Repl};
+use crate::{checked::Arithmetic, collections::HashMap, ranges::RangeMap};
+
+const ARITHMETIC: Arithmetic = Arithmetic::new(5);
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum Category {
+pub enum Category {
     Seed,
     Soil,
     Fertilizer,
@@ -30,28 +35,9 @@ impl Category {
 }
 
 #[derive(Debug)]
-struct Mapping {
-    /// Ranges with source to destination
-    ranges: Vec<(Range<u64>, Range<u64>)>,
-}
-
-impl Mapping {
-    fn lookup(&self, source: u64) -> u64 {
-        for (i, j) in &self.ranges {
-            if i.contains(&source) {
-                let index_i = source - i.start;
-                let destination = j.start + index_i;
-                return destination;
-            }
-        }
-        source
-    }
-}
-
-#[derive(Debug)]
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<u64>,
-    mappings: HashMap<(Category, Category), Mapping>,
+    mappings: HashMap<(Category, Category), RangeMap>,
 }
 
 impl Almanac {
@@ -73,7 +59,7 @@ impl Almanac {
                 ))?;
 
             // lookup next value in mapping:
-            current = mapping.lookup(current);
+            current = mapping.apply(current);
 
             // println!("found mapping for source({:?}) -> {:?} mapping: {:?} (new value: {})", source, new_destination, mapping, current);
 
@@ -87,7 +73,7 @@ impl Almanac {
     }
 }
 
-fn parse_contents(contents: &str) -> anyhow::Result<Almanac> {
+pub fn parse_contents(contents: &str) -> anyhow::Result<Almanac> {
     let contents = contents.trim();
     let mut lines = contents.lines();
     let first = lines.next().ok_or(anyhow::anyhow!("Invalid Input!"))?;
@@ -101,18 +87,12 @@ fn parse_contents(contents: &str) -> anyhow::Result<Almanac> {
 
     let mut mappings = HashMap::new();
     let mut current_mapping_key: Option<(Category, Category)> = None;
-    let mut current_mappings: Vec<(Range<u64>, Range<u64>)> = Vec::new();
+    let mut current_mappings = RangeMap::new();
 
     for line in lines {
         if let Some(rindex) = line.find(" map:") {
             if let Some(key) = current_mapping_key {
-                mappings.insert(
-                    key,
-                    Mapping {
-                        ranges: current_mappings.clone(),
-                    },
-                );
-                current_mappings.clear();
+                mappings.insert(key, core::mem::take(&mut current_mappings));
             }
 
             let (source, destination) = line[0..rindex]
@@ -137,49 +117,63 @@ fn parse_contents(contents: &str) -> anyhow::Result<Almanac> {
             let range_length =
                 str::parse::<u64>(range[2]).map_err(|_| anyhow::anyhow!("Invalid Range!"))?;
 
-            current_mappings.push((
-                (source_start)..(source_start + range_length),
-                (destination_start)..(destination_start + range_length),
-            ));
+            let source_end = ARITHMETIC
+                .add("source start + length", source_start, range_length)
+                .map_err(anyhow::Error::msg)?;
+            current_mappings.insert(source_start..source_end, destination_start)?;
         }
     }
 
     if let Some(key) = current_mapping_key {
-        mappings.insert(
-            key,
-            Mapping {
-                ranges: current_mappings.clone(),
-            },
-        );
-        current_mappings.clear();
+        mappings.insert(key, current_mappings);
     }
 
     Ok(Almanac { seeds, mappings })
 }
 
-fn main() {
-    let contents = read_to_string("day5-input.txt").expect("Invalid Input!");
-    let almanac = parse_contents(&contents).expect("Invalid Input!");
-
-    let locations = almanac
+/// Locations of the seeds.
+///
+/// # Errors
+///
+/// If a category has no mapping or on integer overflow.
+pub fn locations(almanac: &Almanac) -> anyhow::Result<Vec<u64>> {
+    almanac
         .seeds
         .iter()
-        .map(|seed| {
-            almanac
-                .lookup(Category::Seed, Category::Location, *seed)
-                .expect("Invalid Seed Lookup!")
-        })
-        .collect::<Vec<u64>>();
-
-    let min_location = locations.iter().min();
+        .map(|seed| almanac.lookup(Category::Seed, Category::Location, *seed))
+        .collect()
+}
 
-    println!("Locations: {locations:#?}");
-    println!("Smallest Locations: {min_location:#?}");
+#[cfg(feature = "std")]
+#[must_use]
+pub fn commands() -> Repl<'static, Almanac> {
+    Repl::new("day5").command(
+        "lookup",
+        "lookup <category> <value> -> <category>",
+        |almanac: &Almanac, args| {
+            let args = args
+                .iter()
+                .copied()
+                .filter(|arg| *arg != "->")
+                .collect::<Vec<_>>();
+            let category = |index: usize| {
+                let name = args
+                    .get(index)
+                    .ok_or(anyhow::anyhow!("Missing Category!"))?;
+                Category::from_string(name).ok_or(anyhow::anyhow!("Invalid Category {name}!"))
+            };
+            let value = repl::arg::<u64>(&args, 1, "value")?;
+            Ok(almanac
+                .lookup(category(0)?, category(2)?, value)?
+                .to_string())
+        },
+    )
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_contents, Category, Mapping};
+    use super::{commands, parse_contents, Category};
+    use crate::ranges::RangeMap;
 
     const EXAMPLE_INPUT: &str = "
     seeds: 79 14 55 13
@@ -219,21 +213,21 @@ mod tests {
 
     #[test]
     fn test_mapping_lookup() {
-        let mapping = Mapping {
-            ranges: vec![(98..98 + 2, 50..50 + 2), (50..50 + 48, 52..52 + 48)],
-        };
-        assert_eq!(mapping.lookup(1), 1);
-
-        assert_eq!(mapping.lookup(79), 81);
-        assert_eq!(mapping.lookup(14), 14);
-        assert_eq!(mapping.lookup(55), 57);
-        assert_eq!(mapping.lookup(13), 13);
-
-        assert_eq!(mapping.lookup(96), 98);
-        assert_eq!(mapping.lookup(97), 99);
-        assert_eq!(mapping.lookup(98), 50);
-        assert_eq!(mapping.lookup(99), 51);
-        assert_eq!(mapping.lookup(100), 100); // identity if no range!
+        let mut mapping = RangeMap::new();
+        mapping.insert(98..98 + 2, 50).unwrap();
+        mapping.insert(50..50 + 48, 52).unwrap();
+        assert_eq!(mapping.apply(1), 1);
+
+        assert_eq!(mapping.apply(79), 81);
+        assert_eq!(mapping.apply(14), 14);
+        assert_eq!(mapping.apply(55), 57);
+        assert_eq!(mapping.apply(13), 13);
+
+        assert_eq!(mapping.apply(96), 98);
+        assert_eq!(mapping.apply(97), 99);
+        assert_eq!(mapping.apply(98), 50);
+        assert_eq!(mapping.apply(99), 51);
+        assert_eq!(mapping.apply(100), 100); // identity if no range!
     }
 
     #[test]
@@ -283,4 +277,28 @@ mod tests {
             82
         );
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_repl() {
+        let almanac = parse_contents(EXAMPLE_INPUT).unwrap();
+        let repl = commands();
+        assert_eq!(
+            repl.eval(&almanac, "lookup seed 79 -> location")
+                .unwrap()
+                .unwrap(),
+            "82"
+        );
+        assert_eq!(
+            repl.eval(&almanac, "lookup soil 81 fertilizer")
+                .unwrap()
+                .unwrap(),
+            "81"
+        );
+        assert!(repl
+            .eval(&almanac, "lookup seed 79 -> moon")
+            .unwrap()
+            .is_err());
+        assert!(repl.eval(&almanac, "lookup seed").unwrap().is_err());
+    }
 }
@@ -1,8 +1,16 @@
-#![warn(clippy::pedantic)]
-use std::{collections::HashMap, fs::read_to_string, ops::Range};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{
+    checked::Arithmetic,
+    collections::HashMap,
+    ranges::{RangeMap, RangeSet},
+};
+
+const ARITHMETIC: Arithmetic = Arithmetic::new(5);
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum Category {
+pub enum Category {
     Seed,
     Soil,
     Fertilizer,
@@ -30,86 +38,9 @@ impl Category {
 }
 
 #[derive(Debug)]
-struct Mapping {
-    /// Ranges with source to destination
-    ranges: Vec<(Range<u64>, Range<u64>)>,
-}
-
-impl Mapping {
-    fn lookup_range(&self, source: Range<u64>) -> Vec<Range<u64>> {
-        let mut debug_source_ranges = Vec::new();
-        let mut ranges = Vec::new();
-
-        let mut start = source.start;
-        let end = source.end;
-
-        let mut lookup = self.ranges.clone();
-        lookup.sort_by(|(i, _), (j, _)| j.start.partial_cmp(&i.start).unwrap());
-
-        while let Some((source, destination)) = lookup.pop() {
-            let i = start.max(source.start);
-            let j = end.min(source.end);
-
-            if i < j {
-                // insert identity range for everything before the matching range
-                if i > start {
-                    debug_source_ranges.push(start..i);
-                    ranges.push(start..i);
-                }
-
-                // map within destination
-
-                ranges.push(
-                    (destination.start
-                        + (i64::try_from(source.start)
-                            .expect("error casting source.start to i64!")
-                            - i64::try_from(i).expect("error casting i to i64!"))
-                        .unsigned_abs())
-                        ..(destination.start
-                            + (i64::try_from(j).expect("error casting j to i64!")
-                                - i64::try_from(source.start)
-                                    .expect("error casting source.start to i64!"))
-                            .unsigned_abs()),
-                );
-                debug_source_ranges.push(i..j);
-
-                // reset start to end of current range
-                start = j;
-            }
-        }
-
-        if ranges.is_empty() || start < end {
-            debug_source_ranges.push(start..end);
-            ranges.push(start..end);
-        }
-
-        // sanity checking
-        let length = source.end - source.start;
-        assert_eq!(
-            debug_source_ranges
-                .iter()
-                .map(|range| range.end - range.start)
-                .sum::<u64>(),
-            length,
-            "Invalid source mapping range length!"
-        );
-        assert_eq!(
-            ranges
-                .iter()
-                .map(|range| range.end - range.start)
-                .sum::<u64>(),
-            length,
-            "Invalid mapped ranges total length!"
-        );
-
-        ranges
-    }
-}
-
-#[derive(Debug)]
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<Range<u64>>,
-    mappings: HashMap<(Category, Category), Mapping>,
+    mappings: HashMap<(Category, Category), RangeMap>,
 }
 
 impl Almanac {
@@ -120,8 +51,8 @@ impl Almanac {
         source: Category,
         destination: Category,
         range: Range<u64>,
-    ) -> anyhow::Result<Vec<Range<u64>>> {
-        let mut current = vec![range];
+    ) -> anyhow::Result<RangeSet<u64>> {
+        let mut current = RangeSet::from_iter([range]);
         let mut source = source;
         loop {
             let ((_, new_destination), mapping) = self
@@ -134,10 +65,7 @@ impl Almanac {
                 ))?;
 
             // lookup next value in mapping:
-            current = current
-                .iter()
-                .flat_map(|range| mapping.lookup_range(range.clone()))
-                .collect();
+            current = mapping.apply_set(&current);
 
             if *new_destination == destination {
                 return Ok(current);
@@ -149,10 +77,25 @@ impl Almanac {
     }
 }
 
+/// Locations of the seed ranges.
+///
+/// # Errors
+///
+/// If a category has no mapping or on integer overflow.
+pub fn locations(almanac: &Almanac) -> anyhow::Result<RangeSet<u64>> {
+    almanac
+        .seeds
+        .iter()
+        .map(|range| almanac.lookup_range(Category::Seed, Category::Location, range.clone()))
+        .try_fold(RangeSet::new(), |locations, range| {
+            Ok(locations.union(&range?))
+        })
+}
+
 fn create_pairs_from_iter<T>(
     mut iterator: impl Iterator<Item = T>,
 ) -> impl Iterator<Item = (T, T)> {
-    std::iter::from_fn(move || {
+    core::iter::from_fn(move || {
         if let (Some(first), Some(second)) = (iterator.next(), iterator.next()) {
             Some((first, second))
         } else {
@@ -161,7 +104,7 @@ fn create_pairs_from_iter<T>(
     })
 }
 
-fn parse_contents(contents: &str) -> anyhow::Result<Almanac> {
+pub fn parse_contents(contents: &str) -> anyhow::Result<Almanac> {
     let contents = contents.trim();
     let mut lines = contents.lines();
     let first = lines.next().ok_or(anyhow::anyhow!("Invalid Input!"))?;
@@ -173,23 +116,22 @@ fn parse_contents(contents: &str) -> anyhow::Result<Almanac> {
             .split(' ')
             .flat_map(str::parse::<u64>),
     )
-    .map(|(start, length)| start..start + length)
-    .collect::<Vec<Range<u64>>>();
+    .map(|(start, length)| {
+        Ok(start
+            ..ARITHMETIC
+                .add("seed start + length", start, length)
+                .map_err(anyhow::Error::msg)?)
+    })
+    .collect::<anyhow::Result<Vec<Range<u64>>>>()?;
 
     let mut mappings = HashMap::new();
     let mut current_mapping_key: Option<(Category, Category)> = None;
-    let mut current_mappings: Vec<(Range<u64>, Range<u64>)> = Vec::new();
+    let mut current_mappings = RangeMap::new();
 
     for line in lines {
         if let Some(rindex) = line.find(" map:") {
             if let Some(key) = current_mapping_key {
-                mappings.insert(
-                    key,
-                    Mapping {
-                        ranges: current_mappings.clone(),
-                    },
-                );
-                current_mappings.clear();
+                mappings.insert(key, core::mem::take(&mut current_mappings));
             }
 
             let (source, destination) = line[0..rindex]
@@ -214,47 +156,24 @@ fn parse_contents(contents: &str) -> anyhow::Result<Almanac> {
             let range_length =
                 str::parse::<u64>(range[2]).map_err(|_| anyhow::anyhow!("Invalid Range!"))?;
 
-            current_mappings.push((
-                (source_start)..(source_start + range_length),
-                (destination_start)..(destination_start + range_length),
-            ));
+            let source_end = ARITHMETIC
+                .add("source start + length", source_start, range_length)
+                .map_err(anyhow::Error::msg)?;
+            current_mappings.insert(source_start..source_end, destination_start)?;
         }
     }
 
     if let Some(key) = current_mapping_key {
-        mappings.insert(
-            key,
-            Mapping {
-                ranges: current_mappings.clone(),
-            },
-        );
-        current_mappings.clear();
+        mappings.insert(key, current_mappings);
     }
 
     Ok(Almanac { seeds, mappings })
 }
 
-fn main() {
-    let contents = read_to_string("day5-input.txt").expect("Invalid Input!");
-    let almanac = parse_contents(&contents).expect("Invalid Input!");
-
-    let locations = almanac
-        .seeds
-        .iter()
-        .flat_map(|range| {
-            almanac.lookup_range(Category::Seed, Category::Location, range.clone()).expect("Invalid Seed Lookup!")
-        })
-        .collect::<Vec<Range<u64>>>();
-
-    let min_location = locations.iter().min_by_key(|range| range.start).unwrap();
-
-    // println!("Locations: {locations:#?}");
-    println!("Smallest Range Start: {:#?}", min_location.start);
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::{parse_contents, Category, Mapping};
+    use super::{parse_contents, Category};
+    use crate::ranges::RangeMap;
 
     const EXAMPLE_INPUT: &str = "
     seeds: 79 14 55 13
@@ -294,17 +213,15 @@ mod tests {
 
     #[test]
     fn test_mapping_lookup_range() {
-        let mapping = Mapping {
-            ranges: vec![(98..98 + 2, 50..50 + 2), (50..50 + 48, 52..52 + 48)],
-        };
+        let mut mapping = RangeMap::new();
+        mapping.insert(98..98 + 2, 50).unwrap();
+        mapping.insert(50..50 + 48, 52).unwrap();
 
         let lookup_with_range = |source: u64| {
             #[allow(clippy::range_plus_one)]
-            let ranges = mapping.lookup_range(source..source + 1);
-            assert_eq!(ranges.len(), 1);
-            let values = ranges.first().cloned().unwrap().collect::<Vec<_>>();
-            assert_eq!(values.len(), 1);
-            *values.first().unwrap()
+            let ranges = mapping.apply_range(source..source + 1);
+            assert_eq!(ranges.count(), 1);
+            ranges.min().unwrap()
         };
 
         assert_eq!(lookup_with_range(79), 81);
@@ -322,10 +239,9 @@ mod tests {
         assert_eq!(lookup_with_range(99), 51);
         assert_eq!(lookup_with_range(100), 100); // identity if no range!
 
-        let mapping = Mapping {
-            ranges: vec![(5..10, 20..25)],
-        };
-        mapping.lookup_range(1..30);
+        let mut mapping = RangeMap::new();
+        mapping.insert(5..10, 20).unwrap();
+        assert_eq!(mapping.apply_range(1..30).ranges(), &[1..5, 10..30]);
     }
 
     #[test]
@@ -342,10 +258,8 @@ mod tests {
             #[allow(clippy::range_plus_one)]
             let range = value..(value + 1);
             let ranges = almanac.lookup_range(source, destination, range).unwrap();
-            assert_eq!(ranges.len(), 1);
-            let values = ranges.first().cloned().unwrap().collect::<Vec<_>>();
-            assert_eq!(values.len(), 1);
-            *values.first().unwrap()
+            assert_eq!(ranges.count(), 1);
+            ranges.min().unwrap()
         };
 
         assert_eq!(lookup_with_value(Category::Seed, Category::Soil, 79), 81);
@@ -0,0 +1,26 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    input::{read_input, repl_requested},
+    year2023::day5::part1::{commands, locations, parse_contents},
+};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 5)?;
+    let almanac = parse_contents(&contents)?;
+
+    if repl_requested() {
+        commands().run(&almanac, std::io::stdin().lock(), std::io::stdout())?;
+        return Ok(());
+    }
+
+    let locations = locations(&almanac)?;
+
+    let min_location = locations
+        .iter()
+        .min()
+        .ok_or(anyhow::anyhow!("No Locations!"))?;
+
+    println!("Locations: {locations:#?}");
+    println!("Smallest Location: {min_location}");
+    Ok(())
+}
@@ -0,0 +1,18 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    input::read_input,
+    year2023::day5::part2::{locations, parse_contents},
+};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 5)?;
+    let almanac = parse_contents(&contents)?;
+
+    let locations = locations(&almanac)?;
+
+    let min_location = locations.min().ok_or(anyhow::anyhow!("No Locations!"))?;
+
+    // println!("Locations: {locations:#?}");
+    println!("Smallest Range Start: {min_location:#?}");
+    Ok(())
+}
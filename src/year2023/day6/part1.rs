@@ -1,8 +1,11 @@
-#![warn(clippy::pedantic)]
-use std::fs::read_to_string;
+use alloc::vec::Vec;
+
+use crate::checked::{Arithmetic, Overflow};
+
+const ARITHMETIC: Arithmetic = Arithmetic::new(6);
 
 #[derive(Debug, PartialEq, Eq)]
-struct Race {
+pub struct Race {
     /// Time available in race in ms
     time: u32,
     /// Record distance traveled in mm
@@ -11,15 +14,23 @@ struct Race {
 
 impl Race {
     /// Find all possible button press durations with their resulting distances
-    fn all_button_presses(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+    fn all_button_presses(&self) -> impl Iterator<Item = Result<(u32, u32), Overflow>> + '_ {
         assert!(self.time != 0, "Race of zero time!");
-        (1..=self.time).map(|duration| (duration, (self.time - duration) * duration))
+        (1..=self.time).map(|duration| {
+            let distance = ARITHMETIC.mul(
+                "(time - duration) * duration",
+                self.time - duration,
+                duration,
+            )?;
+            Ok((duration, distance))
+        })
     }
 
-    fn count_faster(&self) -> usize {
-        self.all_button_presses()
-            .filter(|(_, new_distance)| *new_distance > self.distance)
-            .count()
+    fn count_faster(&self) -> Result<usize, Overflow> {
+        self.all_button_presses().try_fold(0, |count, press| {
+            let (_, new_distance) = press?;
+            Ok(count + usize::from(new_distance > self.distance))
+        })
     }
 }
 
@@ -34,7 +45,7 @@ fn parse_numbers(line: &str, suffix: &'static str) -> anyhow::Result<Vec<u32>> {
     }
 }
 
-fn parse_contents(contents: &str) -> anyhow::Result<Vec<Race>> {
+pub fn parse_contents(contents: &str) -> anyhow::Result<Vec<Race>> {
     let mut lines = contents.trim().lines();
     let times = parse_numbers(
         lines
@@ -62,20 +73,20 @@ fn parse_contents(contents: &str) -> anyhow::Result<Vec<Race>> {
     }
 }
 
-fn main() {
-    let contents = read_to_string("day6-input.txt").expect("Invalid Input!");
-    let races = parse_contents(&contents).expect("Invalid Input!");
-    let solution = races
-        .iter()
-        .map(Race::count_faster)
-        .reduce(|a, b| a * b)
-        .unwrap();
-    println!("Solution: {solution}");
+/// Product of the numbers of ways to beat the record of each race.
+///
+/// # Errors
+///
+/// On integer overflow.
+pub fn product_of_ways_to_win(races: &[Race]) -> Result<usize, Overflow> {
+    races.iter().try_fold(1, |product, race| {
+        ARITHMETIC.mul("product of ways to win", product, race.count_faster()?)
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{parse_contents, Race};
+    use super::{parse_contents, Race};
 
     const EXAMPLE_INPUT: &str = "
     Time:      7  15   30
@@ -109,7 +120,11 @@ mod tests {
         let races = parse_contents(EXAMPLE_INPUT).unwrap();
         let first = races
             .iter()
-            .map(|race| race.all_button_presses().collect::<Vec<_>>())
+            .map(|race| {
+                race.all_button_presses()
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap()
+            })
             .next()
             .unwrap();
         assert_eq!(
@@ -129,7 +144,11 @@ mod tests {
     #[test]
     fn test_count_faster() {
         let races = parse_contents(EXAMPLE_INPUT).unwrap();
-        let faster = races.iter().map(Race::count_faster).collect::<Vec<_>>();
+        let faster = races
+            .iter()
+            .map(Race::count_faster)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
         assert_eq!(faster, [4, 8, 9]);
     }
 }
@@ -1,8 +1,12 @@
-#![warn(clippy::pedantic)]
-use std::fs::read_to_string;
+use crate::{
+    checked::{Arithmetic, Overflow},
+    math,
+};
+
+const ARITHMETIC: Arithmetic = Arithmetic::new(6);
 
 #[derive(Debug, PartialEq, Eq)]
-struct Race {
+pub struct Race {
     /// Time available in race in ms
     time: u64,
     /// Record distance traveled in mm
@@ -10,17 +14,16 @@ struct Race {
 }
 
 impl Race {
-    fn count_faster_bounds(&self) -> u64 {
-        let range = 1..=self.time;
-        let find_faster = |duration: &u64| {
-            let new_distance = (self.time - duration) * duration;
-            new_distance > self.distance
+    /// Holding the button for `x` ms travels `x * (time - x)`, so the faster durations
+    /// are the integers strictly between the roots of `x² - time·x + distance`.
+    pub fn count_faster_bounds(&self) -> Result<u64, Overflow> {
+        let time = ARITHMETIC.convert::<_, i64>("time cast to i64", self.time)?;
+        let distance = ARITHMETIC.convert("distance cast to i64", self.distance)?;
+        let Some(range) = math::quadratic_negative_range(-time, distance) else {
+            return Ok(0);
         };
-
-        let lower = range.clone().find(find_faster).unwrap();
-        let upper = range.rev().find(find_faster).unwrap();
-
-        upper - lower + 1
+        let width = ARITHMETIC.sub("end - start", *range.end(), *range.start())?;
+        Ok(ARITHMETIC.add("width + 1", width, 1)?.unsigned_abs())
     }
 }
 
@@ -35,7 +38,7 @@ fn parse_number(line: &str, suffix: &'static str) -> anyhow::Result<u64> {
     }
 }
 
-fn parse_contents(contents: &str) -> anyhow::Result<Race> {
+pub fn parse_contents(contents: &str) -> anyhow::Result<Race> {
     let mut lines = contents.trim().lines();
     let time = parse_number(
         lines
@@ -55,16 +58,9 @@ fn parse_contents(contents: &str) -> anyhow::Result<Race> {
     Ok(Race { time, distance })
 }
 
-fn main() {
-    let contents = read_to_string("day6-input.txt").expect("Invalid Input!");
-    let race = parse_contents(&contents).expect("Invalid Input!");
-    let solution = race.count_faster_bounds();
-    println!("Solution: {solution}");
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::{parse_contents, Race};
+    use super::{parse_contents, Race};
 
     const EXAMPLE_INPUT: &str = "
     Time:      7  15   30
@@ -86,6 +82,6 @@ mod tests {
     #[test]
     fn test_count_faster_bounds() {
         let race = parse_contents(EXAMPLE_INPUT).unwrap();
-        assert_eq!(race.count_faster_bounds(), 71503);
+        assert_eq!(race.count_faster_bounds(), Ok(71503));
     }
 }
@@ -0,0 +1,13 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    input::read_input,
+    year2023::day6::part1::{parse_contents, product_of_ways_to_win},
+};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 6)?;
+    let races = parse_contents(&contents)?;
+    let solution = product_of_ways_to_win(&races)?;
+    println!("Solution: {solution}");
+    Ok(())
+}
@@ -0,0 +1,10 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{input::read_input, year2023::day6::part2::parse_contents};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 6)?;
+    let race = parse_contents(&contents)?;
+    let solution = race.count_faster_bounds()?;
+    println!("Solution: {solution}");
+    Ok(())
+}
@@ -1,9 +1,17 @@
-#![warn(clippy::pedantic)]
-use std::{collections::HashMap, fs::read_to_string};
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::repl::Repl;
+use crate::{
+    checked::{Arithmetic, Overflow},
+    collections::HashMap,
+};
+
+const ARITHMETIC: Arithmetic = Arithmetic::new(7);
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
-enum Kind {
+pub enum Kind {
     FiveOfAKind,
     FourOfAKind,
     FullHouse,
@@ -45,7 +53,7 @@ impl Kind {
 }
 
 #[derive(Debug, Default, Clone, Copy)]
-struct Hand {
+pub struct Hand {
     cards: [char; 5],
     kind: Kind,
 }
@@ -56,7 +64,7 @@ impl Hand {
     ];
 }
 
-fn parse_hand(hand: &str) -> Hand {
+pub fn parse_hand(hand: &str) -> Hand {
     assert_eq!(hand.len(), 5);
     let mut counts: HashMap<char, u32> = HashMap::new();
     let mut cards = ['X'; 5];
@@ -71,7 +79,7 @@ fn parse_hand(hand: &str) -> Hand {
     Hand { cards, kind }
 }
 
-fn parse_contents(contents: &str) -> impl Iterator<Item = (Hand, u32)> + '_ {
+pub fn parse_contents(contents: &str) -> impl Iterator<Item = (Hand, u32)> + '_ {
     contents.trim().lines().map(|line| {
         let (hand, bid) = line.trim().split_once(' ').expect("Invalid Input!");
         (
@@ -81,7 +89,7 @@ fn parse_contents(contents: &str) -> impl Iterator<Item = (Hand, u32)> + '_ {
     })
 }
 
-fn sort_hands(hands: &mut [(Hand, u32)]) {
+pub fn sort_hands(hands: &mut [(Hand, u32)]) {
     hands.sort_by_key(|(hand, _)| {
         let kind = Kind::ORDER
             .iter()
@@ -103,28 +111,39 @@ fn sort_hands(hands: &mut [(Hand, u32)]) {
     hands.reverse();
 }
 
-fn calculate_winnings(hands: &[(Hand, u32)]) -> u32 {
+pub fn calculate_winnings(hands: &[(Hand, u32)]) -> Result<u32, Overflow> {
     hands
         .iter()
         .enumerate()
-        .map(|(index, (_, bid))| {
-            let rank = u32::try_from(index).expect("rank cast to u32 error") + 1;
-            bid * rank
+        .try_fold(0, |winnings, (index, (_, bid))| {
+            let rank = ARITHMETIC.convert("rank", index + 1)?;
+            let winning = ARITHMETIC.mul("bid * rank", *bid, rank)?;
+            ARITHMETIC.add("sum of winnings", winnings, winning)
         })
-        .sum()
 }
 
-fn main() {
-    let contents = read_to_string("day7-input.txt").expect("Invalid Input!");
-    let mut cards = parse_contents(&contents).collect::<Vec<_>>();
-    sort_hands(&mut cards);
-    let winnings = calculate_winnings(&cards);
-    println!("Winnings: {winnings}");
+#[cfg(feature = "std")]
+#[must_use]
+pub fn commands() -> Repl<'static, Vec<(Hand, u32)>> {
+    Repl::new("day7").command("rank", "rank <hand>", |hands: &Vec<(Hand, u32)>, args| {
+        let hand = args.first().ok_or(anyhow::anyhow!("Missing Hand!"))?;
+        let (index, (found, bid)) = hands
+            .iter()
+            .enumerate()
+            .find(|(_, (found, _))| found.cards.iter().copied().eq(hand.chars()))
+            .ok_or(anyhow::anyhow!("Unknown Hand {hand}!"))?;
+        Ok(alloc::format!(
+            "rank {} of {} ({:?}, bid {bid})",
+            index + 1,
+            hands.len(),
+            found.kind
+        ))
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{calculate_winnings, parse_contents, sort_hands, Hand, Kind};
+    use super::{calculate_winnings, commands, parse_contents, sort_hands, Hand, Kind};
 
     const EXAMPLE_INPUT: &str = "
     32T3K 765
@@ -166,7 +185,24 @@ mod tests {
     fn test_calculate_winnings() {
         let mut cards = parse_contents(EXAMPLE_INPUT).collect::<Vec<(Hand, u32)>>();
         sort_hands(&mut cards);
-        let winnings = calculate_winnings(&cards);
+        let winnings = calculate_winnings(&cards).expect("Integer Overflow!");
         assert_eq!(winnings, 6440);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_repl() {
+        let mut hands = parse_contents(EXAMPLE_INPUT).collect::<Vec<_>>();
+        sort_hands(&mut hands);
+        let repl = commands();
+        assert_eq!(
+            repl.eval(&hands, "rank 32T3K").unwrap().unwrap(),
+            "rank 1 of 5 (OnePair, bid 765)"
+        );
+        assert_eq!(
+            repl.eval(&hands, "rank QQQJA").unwrap().unwrap(),
+            "rank 5 of 5 (ThreeOfAKind, bid 483)"
+        );
+        assert!(repl.eval(&hands, "rank AAAAA").unwrap().is_err());
+    }
 }
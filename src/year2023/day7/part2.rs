@@ -1,9 +1,15 @@
-#![warn(clippy::pedantic)]
-use std::{collections::HashMap, fs::read_to_string};
+use alloc::vec::Vec;
+
+use crate::{
+    checked::{Arithmetic, Overflow},
+    collections::HashMap,
+};
+
+const ARITHMETIC: Arithmetic = Arithmetic::new(7);
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
-enum Kind {
+pub enum Kind {
     FiveOfAKind,
     FourOfAKind,
     FullHouse,
@@ -45,7 +51,7 @@ impl Kind {
 }
 
 #[derive(Debug, Default, Clone, Copy)]
-struct Hand {
+pub struct Hand {
     cards: [char; 5],
     kind: Kind,
 }
@@ -56,7 +62,7 @@ impl Hand {
     ];
 }
 
-fn parse_hand(hand: &str) -> Hand {
+pub fn parse_hand(hand: &str) -> Hand {
     assert_eq!(hand.len(), 5);
     let mut counts: HashMap<char, u32> = HashMap::new();
     let mut cards = ['X'; 5];
@@ -81,7 +87,7 @@ fn parse_hand(hand: &str) -> Hand {
     Hand { cards, kind }
 }
 
-fn parse_contents(contents: &str) -> impl Iterator<Item = (Hand, u32)> + '_ {
+pub fn parse_contents(contents: &str) -> impl Iterator<Item = (Hand, u32)> + '_ {
     contents.trim().lines().map(|line| {
         let (hand, bid) = line.trim().split_once(' ').expect("Invalid Input!");
         (
@@ -91,7 +97,7 @@ fn parse_contents(contents: &str) -> impl Iterator<Item = (Hand, u32)> + '_ {
     })
 }
 
-fn sort_hands(hands: &mut [(Hand, u32)]) {
+pub fn sort_hands(hands: &mut [(Hand, u32)]) {
     hands.sort_by_key(|(hand, _)| {
         let kind = Kind::ORDER
             .iter()
@@ -113,28 +119,20 @@ fn sort_hands(hands: &mut [(Hand, u32)]) {
     hands.reverse();
 }
 
-fn calculate_winnings(hands: &[(Hand, u32)]) -> u32 {
+pub fn calculate_winnings(hands: &[(Hand, u32)]) -> Result<u32, Overflow> {
     hands
         .iter()
         .enumerate()
-        .map(|(index, (_, bid))| {
-            let rank = u32::try_from(index).expect("rank cast to u32 error") + 1;
-            bid * rank
+        .try_fold(0, |winnings, (index, (_, bid))| {
+            let rank = ARITHMETIC.convert("rank", index + 1)?;
+            let winning = ARITHMETIC.mul("bid * rank", *bid, rank)?;
+            ARITHMETIC.add("sum of winnings", winnings, winning)
         })
-        .sum()
-}
-
-fn main() {
-    let contents = read_to_string("day7-input.txt").expect("Invalid Input!");
-    let mut cards = parse_contents(&contents).collect::<Vec<_>>();
-    sort_hands(&mut cards);
-    let winnings = calculate_winnings(&cards);
-    println!("Winnings: {winnings}");
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{calculate_winnings, parse_contents, sort_hands, Hand, Kind};
+    use super::{calculate_winnings, parse_contents, sort_hands, Hand, Kind};
 
     const EXAMPLE_INPUT: &str = "
     32T3K 765
@@ -176,7 +174,7 @@ mod tests {
     fn test_calculate_winnings() {
         let mut cards = parse_contents(EXAMPLE_INPUT).collect::<Vec<(Hand, u32)>>();
         sort_hands(&mut cards);
-        let winnings = calculate_winnings(&cards);
+        let winnings = calculate_winnings(&cards).expect("Integer Overflow!");
         assert_eq!(winnings, 5905);
     }
 }
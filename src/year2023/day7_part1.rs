@@ -0,0 +1,20 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    input::{read_input, repl_requested},
+    year2023::day7::part1::{calculate_winnings, commands, parse_contents, sort_hands},
+};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 7)?;
+    let mut cards = parse_contents(&contents).collect::<Vec<_>>();
+    sort_hands(&mut cards);
+
+    if repl_requested() {
+        commands().run(&cards, std::io::stdin().lock(), std::io::stdout())?;
+        return Ok(());
+    }
+
+    let winnings = calculate_winnings(&cards)?;
+    println!("Winnings: {winnings}");
+    Ok(())
+}
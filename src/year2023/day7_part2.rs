@@ -0,0 +1,14 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    input::read_input,
+    year2023::day7::part2::{calculate_winnings, parse_contents, sort_hands},
+};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 7)?;
+    let mut cards = parse_contents(&contents).collect::<Vec<_>>();
+    sort_hands(&mut cards);
+    let winnings = calculate_winnings(&cards)?;
+    println!("Winnings: {winnings}");
+    Ok(())
+}
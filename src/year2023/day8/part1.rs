@@ -0,0 +1,140 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::graph;
+#[cfg(feature = "std")]
+use crate::repl::Repl;
+
+#[derive(Debug, Default)]
+pub struct Tree<'a> {
+    instructions: &'a str,
+    nodes: BTreeMap<&'a str, (&'a str, &'a str)>,
+}
+
+impl<'a> Tree<'a> {
+    /// Follow the left/right instructions from a node until the destination is reached,
+    /// the walk state is the current node together with the instruction pointer.
+    pub fn traverse(&self, from: &'a str, to: &'a str) -> Option<Vec<&'a str>> {
+        let instructions = self.instructions.as_bytes();
+        let path = graph::walk_until(
+            (from, 0),
+            |&(node, ip)| {
+                let (left, right) = self.nodes.get(node)?;
+                let child = if instructions[ip] == b'L' {
+                    left
+                } else {
+                    right
+                };
+                Some((*child, (ip + 1) % instructions.len()))
+            },
+            |&(node, _)| node == to,
+        )?;
+
+        Some(path.into_iter().map(|(node, _)| node).collect())
+    }
+}
+
+pub fn parse_contents(contents: &str) -> Tree<'_> {
+    let mut lines = contents.trim().lines();
+    Tree {
+        instructions: lines.next().expect("Invalid Instruction Input!"),
+        nodes: lines
+            .filter_map(|line| {
+                if let Some((label, children)) = line.trim().split_once(" = ") {
+                    let (left_child, right_child) = children[1..children.len() - 1]
+                        .split_once(", ")
+                        .expect("Invalid left/right children!");
+                    Some((label, (left_child, right_child)))
+                } else {
+                    None
+                }
+            })
+            .collect::<BTreeMap<&str, (&str, &str)>>(),
+    }
+}
+
+#[cfg(feature = "std")]
+#[must_use]
+pub fn commands<'a>() -> Repl<'static, Tree<'a>> {
+    Repl::new("day8").command("path", "path <from> <to>", |tree: &Tree<'a>, args| {
+        // the nodes of the tree outlive the arguments of the command
+        let node = |index: usize| {
+            let label = args.get(index).ok_or(anyhow::anyhow!("Missing Node!"))?;
+            tree.nodes
+                .get_key_value(label)
+                .map(|(node, _)| *node)
+                .ok_or(anyhow::anyhow!("Unknown Node {label}!"))
+        };
+        let path = tree
+            .traverse(node(0)?, node(1)?)
+            .ok_or(anyhow::anyhow!("No path found!"))?;
+        Ok(alloc::format!(
+            "{} steps: {}",
+            path.len() - 1,
+            path.join(" -> ")
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{commands, parse_contents};
+
+    const EXAMPLE_INPUT_1: &str = "
+    RL
+
+    AAA = (BBB, CCC)
+    BBB = (DDD, EEE)
+    CCC = (ZZZ, GGG)
+    DDD = (DDD, DDD)
+    EEE = (EEE, EEE)
+    GGG = (GGG, GGG)
+    ZZZ = (ZZZ, ZZZ)
+    ";
+
+    const EXAMPLE_INPUT_2: &str = "
+    LLR
+
+    AAA = (BBB, BBB)
+    BBB = (AAA, ZZZ)
+    ZZZ = (ZZZ, ZZZ)
+    ";
+
+    #[test]
+    fn test_parse_contents() {
+        let tree = parse_contents(EXAMPLE_INPUT_1);
+        assert_eq!(tree.instructions, "RL");
+        assert_eq!(tree.nodes.len(), 7);
+
+        let tree = parse_contents(EXAMPLE_INPUT_2);
+        assert_eq!(tree.instructions, "LLR");
+        assert_eq!(tree.nodes.len(), 3);
+    }
+
+    #[test]
+    fn test_traverse() {
+        let tree = parse_contents(EXAMPLE_INPUT_1);
+        let path = tree.traverse("AAA", "ZZZ").unwrap();
+        assert_eq!(path, ["AAA", "CCC", "ZZZ"]);
+
+        let tree = parse_contents(EXAMPLE_INPUT_2);
+        let path = tree.traverse("AAA", "ZZZ").unwrap();
+        assert_eq!(path, ["AAA", "BBB", "AAA", "BBB", "AAA", "BBB", "ZZZ"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_repl() {
+        let tree = parse_contents(EXAMPLE_INPUT_2);
+        let repl = commands();
+        assert_eq!(
+            repl.eval(&tree, "path AAA ZZZ").unwrap().unwrap(),
+            "6 steps: AAA -> BBB -> AAA -> BBB -> AAA -> BBB -> ZZZ"
+        );
+        assert_eq!(
+            repl.eval(&tree, "path BBB BBB").unwrap().unwrap(),
+            "0 steps: BBB"
+        );
+        assert!(repl.eval(&tree, "path AAA XXX").unwrap().is_err());
+        assert!(repl.eval(&tree, "path ZZZ AAA").unwrap().is_err());
+    }
+}
@@ -1,24 +1,9 @@
-#![warn(clippy::pedantic)]
-use std::{collections::BTreeMap, fs::read_to_string};
+use alloc::{collections::BTreeMap, vec::Vec};
 
-fn gcd(a: u64, b: u64) -> u64 {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
-    }
-}
-
-fn lcm(a: u64, b: u64) -> u64 {
-    a / gcd(a, b) * b
-}
-
-fn lcm_list(numbers: &[u64]) -> u64 {
-    numbers.iter().fold(1, |l, &n| lcm(l, n))
-}
+use crate::{answer::Answer, graph};
 
 #[derive(Debug, Default)]
-struct Tree<'a> {
+pub struct Tree<'a> {
     instructions: &'a str,
     nodes: BTreeMap<&'a str, (&'a str, &'a str)>,
 }
@@ -32,38 +17,38 @@ impl<'a> Tree<'a> {
             .collect::<Vec<&'a str>>()
     }
 
-    fn traverse(&self, from: char, to: char) -> u64 {
+    /// Walk from every node ending with `from` until a node ending with `to` is reached,
+    /// all walks end up at their destination simultaneously after the least common multiple of steps.
+    /// The multiple of many walks outgrows any integer type, so it is counted as answer.
+    pub fn traverse(&self, from: char, to: char) -> Option<Answer> {
+        let instructions = self.instructions.as_bytes();
         let mut steps = Vec::new();
         for start_node in self.ends_with(from) {
-            let mut step = 0;
-            let mut ip = 0;
-            let mut q = vec![start_node];
-
-            while let Some(next) = q.pop() {
-                step += 1;
-                if next.ends_with(to) {
-                    break;
-                }
-
-                if let Some((left, right)) = self.nodes.get(next) {
-                    let instr = self.instructions.chars().nth(ip).unwrap();
-                    let child = if instr == 'L' { left } else { right };
-                    q.push(child);
-
-                    ip += 1;
-                    if ip >= self.instructions.len() {
-                        ip = 0;
-                    }
-                }
-            }
-            steps.push(step - 1);
+            let path = graph::walk_until(
+                (start_node, 0),
+                |&(node, ip)| {
+                    let (left, right) = self.nodes.get(node)?;
+                    let child = if instructions[ip] == b'L' {
+                        left
+                    } else {
+                        right
+                    };
+                    Some((*child, (ip + 1) % instructions.len()))
+                },
+                |&(node, _)| node.ends_with(to),
+            )?;
+            steps.push(Answer::from(path.len() - 1));
         }
 
-        lcm_list(&steps)
+        Some(
+            steps
+                .iter()
+                .fold(Answer::from(1u8), |lcm, steps| lcm.lcm(steps)),
+        )
     }
 }
 
-fn parse_contents(contents: &str) -> Tree {
+pub fn parse_contents(contents: &str) -> Tree<'_> {
     let mut lines = contents.trim().lines();
     Tree {
         instructions: lines.next().expect("Invalid Instruction Input!"),
@@ -82,16 +67,10 @@ fn parse_contents(contents: &str) -> Tree {
     }
 }
 
-fn main() {
-    let contents = read_to_string("day8-input.txt").expect("Invalid Input!");
-    let tree = parse_contents(&contents);
-    let path_len = tree.traverse('A', 'Z');
-    println!("Path: {:?}", path_len);
-}
-
 #[cfg(test)]
 mod tests {
-    use crate::parse_contents;
+    use super::parse_contents;
+    use crate::answer::Answer;
 
     const EXAMPLE_INPUT: &str = "
     LR
@@ -116,7 +95,7 @@ mod tests {
     #[test]
     fn test_traverse() {
         let tree = parse_contents(EXAMPLE_INPUT);
-        let path_len = tree.traverse('A', 'Z');
-        assert_eq!(path_len, 6);
+        let path_len = tree.traverse('A', 'Z').unwrap();
+        assert_eq!(path_len, Answer::from(6u8));
     }
 }
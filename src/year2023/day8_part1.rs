@@ -0,0 +1,21 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    input::{read_input, repl_requested},
+    year2023::day8::part1::{commands, parse_contents},
+};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 8)?;
+    let tree = parse_contents(&contents);
+
+    if repl_requested() {
+        commands().run(&tree, std::io::stdin().lock(), std::io::stdout())?;
+        return Ok(());
+    }
+
+    let path = tree
+        .traverse("AAA", "ZZZ")
+        .ok_or(anyhow::anyhow!("No path found!"))?;
+    println!("Path: {:?}", path.len() - 1);
+    Ok(())
+}
@@ -0,0 +1,12 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{input::read_input, year2023::day8::part2::parse_contents};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 8)?;
+    let tree = parse_contents(&contents);
+    let path_len = tree
+        .traverse('A', 'Z')
+        .ok_or(anyhow::anyhow!("No path found!"))?;
+    println!("Path: {path_len}");
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use alloc::vec::Vec;
+
+use crate::checked::{Arithmetic, Overflow};
+
+pub const ARITHMETIC: Arithmetic = Arithmetic::new(9);
+
+pub fn parse_contents(contents: &str) -> Vec<Vec<i64>> {
+    contents
+        .trim()
+        .lines()
+        .map(|line| {
+            line.trim()
+                .split(' ')
+                .map(|number| str::parse::<i64>(number).expect("Invalid Number!"))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Rows of repeated differences of each sequence, like [`crate::math::differences`] but
+/// with the subtractions of the day's arithmetic.
+pub fn differences(numbers: &[Vec<i64>]) -> Result<Vec<Vec<Vec<i64>>>, Overflow> {
+    numbers
+        .iter()
+        .map(|numbers| {
+            let mut rows = Vec::new();
+            let mut current = numbers.clone();
+            loop {
+                current = current
+                    .windows(2)
+                    .map(|pair| ARITHMETIC.sub("difference", pair[1], pair[0]))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if current.iter().all(|n| *n == 0) {
+                    break;
+                }
+                rows.push(current.clone());
+            }
+            Ok(rows)
+        })
+        .collect()
+}
+
+pub fn find_next_numbers(
+    numbers: &[Vec<i64>],
+    diffs: &[Vec<Vec<i64>>],
+) -> Result<Vec<i64>, Overflow> {
+    numbers
+        .iter()
+        .enumerate()
+        .map(|(i, numbers)| {
+            let mut num = 0;
+            for diffs in diffs[i].iter().rev() {
+                let last = diffs.last().unwrap();
+                num = ARITHMETIC.add("sum of the last differences", num, *last)?;
+            }
+            ARITHMETIC.add("next number", *numbers.last().unwrap(), num)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{differences, find_next_numbers, parse_contents};
+
+    const EXAMPLE_INPUT: &str = "
+    0 3 6 9 12 15
+    1 3 6 10 15 21
+    10 13 16 21 30 45
+    ";
+
+    #[test]
+    fn test_parse_contents() {
+        let numbers = parse_contents(EXAMPLE_INPUT);
+        assert_eq!(numbers.len(), 3);
+        assert_eq!(numbers.first().unwrap(), &[0, 3, 6, 9, 12, 15]);
+    }
+
+    #[test]
+    fn test_differences() {
+        let numbers = parse_contents(EXAMPLE_INPUT);
+        let diffs = differences(&numbers).unwrap();
+        assert_eq!(diffs.len(), 3);
+        assert_eq!(diffs.first().unwrap(), &vec![[3, 3, 3, 3, 3]]);
+        assert_eq!(
+            diffs.get(1).unwrap(),
+            &vec![vec![2, 3, 4, 5, 6], vec![1, 1, 1, 1]]
+        );
+    }
+
+    #[test]
+    fn test_find_next_numbers() {
+        let numbers = parse_contents(EXAMPLE_INPUT);
+        let diffs = differences(&numbers).unwrap();
+        let next_numbers = find_next_numbers(&numbers, &diffs).unwrap();
+        assert_eq!(next_numbers, [18, 28, 68]);
+    }
+}
@@ -0,0 +1,15 @@
+#![warn(clippy::pedantic)]
+use adventofcode::{
+    input::read_input,
+    year2023::day9::part1::{differences, find_next_numbers, parse_contents, ARITHMETIC},
+};
+
+fn main() -> anyhow::Result<()> {
+    let contents = read_input(2023, 9)?;
+    let numbers = parse_contents(&contents);
+    let diffs = differences(&numbers)?;
+    let next_numbers = find_next_numbers(&numbers, &diffs)?;
+    let solution = ARITHMETIC.sum("sum of next numbers", next_numbers)?;
+    println!("Solution: {solution}");
+    Ok(())
+}
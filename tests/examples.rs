@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use adventofcode::{examples, puzzle::Puzzle, runner};
+
+/// Runs every solution on the example files and compares the answers from their headers.
+#[test]
+fn test_examples() {
+    let bin_dir = Path::new(env!("CARGO_BIN_EXE_year2023_day1_part1"))
+        .parent()
+        .expect("Invalid Binary Path!");
+    let examples = examples::load_examples(&examples::examples_dir()).unwrap();
+    assert!(!examples.is_empty(), "No examples found!");
+
+    let mut failures = Vec::new();
+    for example in &examples {
+        for (part, expected) in &example.answers {
+            let path = example.path.display();
+            let puzzle = Puzzle::new(example.year, example.day, *part);
+            match runner::run_solution(bin_dir, puzzle, &example.input) {
+                Ok(answer) if answer == *expected => {}
+                Ok(answer) => {
+                    failures.push(format!(
+                        "{path} part{part}: expected {expected}, got {answer}"
+                    ));
+                }
+                Err(err) => failures.push(format!("{path} part{part}: {err}")),
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} example answer(s) wrong:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "serve")]
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+    path::Path,
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use adventofcode::serve::{serve, ServeConfig};
+use tiny_http::Server;
+
+fn start(time_budget: Duration, max_input_size: usize, max_concurrent: usize) -> SocketAddr {
+    let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+    let addr = server.server_addr().to_ip().unwrap();
+    let bin_dir = Path::new(env!("CARGO_BIN_EXE_year2023_day1_part1"))
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    thread::spawn(move || {
+        serve(
+            &server,
+            ServeConfig {
+                bin_dir,
+                time_budget,
+                max_input_size,
+                max_concurrent,
+            },
+        );
+    });
+    addr
+}
+
+/// Sends a request and returns the status code and body of the response.
+fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+         Content-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let status = response
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse().ok())
+        .unwrap();
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+    (status, body.to_string())
+}
+
+#[test]
+fn test_solve() {
+    let addr = start(Duration::from_secs(60), 1024, 8);
+
+    let (status, body) = request(addr, "GET", "/days", "");
+    assert_eq!(status, 200);
+    assert!(body.starts_with(
+        r#"[{"year":2023,"day":1,"parts":[1,2]},{"year":2023,"day":2,"parts":[1,2]}"#
+    ));
+
+    let (status, body) = request(addr, "POST", "/solve/2023/1/1", "1abc2\npqr3stu8vwx\n");
+    assert_eq!(status, 200);
+    assert!(body.starts_with(r#"{"year":2023,"day":1,"part":1,"answer":"50","elapsed_ms":"#));
+
+    // the day without a year is solved for the default year
+    let (status, body) = request(addr, "POST", "/solve/1/1", "1abc2\n");
+    assert_eq!(status, 200);
+    assert!(body.starts_with(r#"{"year":2023,"day":1,"part":1,"answer":"12","#));
+
+    let (status, body) = request(addr, "POST", "/solve/2/1", "Game 1: three purple");
+    assert_eq!(status, 422, "{body}");
+
+    assert_eq!(request(addr, "POST", "/solve/99/1", "").0, 404);
+    assert_eq!(request(addr, "POST", "/solve/2015/1/1", "").0, 404);
+    assert_eq!(request(addr, "POST", "/solve/one/1", "").0, 400);
+    assert_eq!(request(addr, "GET", "/solve/1/1", "").0, 405);
+    assert_eq!(request(addr, "GET", "/", "").0, 404);
+    assert_eq!(
+        request(addr, "POST", "/solve/1/1", &"1".repeat(1025)).0,
+        413
+    );
+}
+
+#[test]
+fn test_time_budget() {
+    let addr = start(Duration::ZERO, 1024, 8);
+    let (status, body) = request(addr, "POST", "/solve/1/1", "1abc2\n");
+    assert_eq!(status, 504);
+    assert!(body.contains("Time budget"), "{body}");
+}
+
+#[test]
+fn test_busy() {
+    let addr = start(Duration::from_secs(60), 1024, 1);
+
+    // a request holding the only slot, waiting for its body after the server asked for it
+    let mut stalled = TcpStream::connect(addr).unwrap();
+    write!(
+        stalled,
+        "POST /solve/1/1 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+         Expect: 100-continue\r\nContent-Length: 6\r\n\r\n"
+    )
+    .unwrap();
+    let mut continue_line = [0; 12];
+    stalled.read_exact(&mut continue_line).unwrap();
+    assert_eq!(&continue_line, b"HTTP/1.1 100");
+
+    let (status, body) = request(addr, "GET", "/days", "");
+    assert_eq!(status, 503);
+    assert!(body.contains("Busy"), "{body}");
+
+    // the slot is released right after the stalled request is answered
+    stalled.write_all(b"1abc2\n").unwrap();
+    let mut response = String::new();
+    stalled.read_to_string(&mut response).unwrap();
+    assert!(response.contains(r#""answer":"12""#), "{response}");
+    let released = (0..100).any(|_| {
+        thread::sleep(Duration::from_millis(10));
+        request(addr, "GET", "/days", "").0 == 200
+    });
+    assert!(released);
+}